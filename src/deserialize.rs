@@ -5,6 +5,7 @@ use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt::{self, Display};
+use core::marker::PhantomData;
 
 use alloc::collections::BTreeSet;
 
@@ -15,30 +16,205 @@ use facet_core::{
 use facet_reflect::{Partial, ReflectError};
 use facet_solver::{PathSegment, Schema, Solver};
 
+use crate::number::JsonNumber;
+use crate::raw::JsonRaw;
+use crate::serialize::ByteEncoding;
 use crate::span::{Span, Spanned};
 use crate::tokenizer::{Token, TokenError, TokenErrorKind, Tokenizer};
+use crate::value::{JsonValue, SpannedJsonValue};
+
+/// Computes the Levenshtein (single-character insert/delete/substitute) edit
+/// distance between two strings.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, &ca) in a.iter().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            let cost = if ca == cb { 0 } else { 1 };
+            let new_val = (above + 1).min(row[j] + 1).min(prev_diag + cost);
+            prev_diag = above;
+            row[j + 1] = new_val;
+        }
+    }
+    row[b.len()]
+}
 
-/// Find the best matching field name from a list of expected fields.
-/// Returns Some(suggestion) if a match with similarity >= 0.6 is found.
+/// Find the best matching name from a list of candidates by Levenshtein edit
+/// distance, the same "did you mean" heuristic rustc's JSON diagnostics use
+/// for suggested text. A candidate is only accepted within
+/// `max(1, candidate.len() / 3)` edits of `unknown`; ties keep whichever
+/// candidate was encountered first.
 fn find_similar_field<'a>(unknown: &str, expected: &[&'a str]) -> Option<&'a str> {
-    let mut best_match: Option<(&'a str, f64)> = None;
+    let mut best_match: Option<(&'a str, usize)> = None;
 
     for &candidate in expected {
-        let similarity = strsim::jaro_winkler(unknown, candidate);
-        if similarity >= 0.6 {
-            if best_match.map_or(true, |(_, best_sim)| similarity > best_sim) {
-                best_match = Some((candidate, similarity));
-            }
+        let distance = levenshtein_distance(unknown, candidate);
+        let threshold = (candidate.len() / 3).max(1);
+        if distance <= threshold
+            && best_match.map_or(true, |(_, best_dist)| distance < best_dist)
+        {
+            best_match = Some((candidate, distance));
         }
     }
 
     best_match.map(|(name, _)| name)
 }
 
+/// Splits a Rust-style `snake_case` identifier into its words.
+fn ident_words(name: &str) -> impl Iterator<Item = &str> {
+    name.split('_').filter(|w| !w.is_empty())
+}
+
+/// Renders a `snake_case` Rust identifier under one of serde's
+/// `#[serde(rename_all = "...")]` case conventions. Unrecognized `case`
+/// strings fall back to the identifier unchanged.
+pub(crate) fn apply_rename_all(field_name: &str, case: &str) -> String {
+    let mut words = ident_words(field_name);
+    let Some(first) = words.next() else {
+        return String::new();
+    };
+    let rest: Vec<&str> = words.collect();
+
+    match case {
+        "camelCase" => {
+            let mut s = String::from(first);
+            for word in &rest {
+                s.push_str(&capitalize(word));
+            }
+            s
+        }
+        "PascalCase" => {
+            let mut s = capitalize(first);
+            for word in &rest {
+                s.push_str(&capitalize(word));
+            }
+            s
+        }
+        "snake_case" => field_name.to_string(),
+        "SCREAMING_SNAKE_CASE" => field_name.to_ascii_uppercase(),
+        "kebab-case" => core::iter::once(first)
+            .chain(rest.iter().copied())
+            .collect::<Vec<_>>()
+            .join("-"),
+        "SCREAMING-KEBAB-CASE" => core::iter::once(first)
+            .chain(rest.iter().copied())
+            .collect::<Vec<_>>()
+            .join("-")
+            .to_ascii_uppercase(),
+        "lowercase" => core::iter::once(first)
+            .chain(rest.iter().copied())
+            .collect::<Vec<_>>()
+            .join(""),
+        "UPPERCASE" => core::iter::once(first)
+            .chain(rest.iter().copied())
+            .collect::<Vec<_>>()
+            .join("")
+            .to_ascii_uppercase(),
+        _ => field_name.to_string(),
+    }
+}
+
+/// Returns `true` if `key` matches `field_name` exactly, under the
+/// container's explicit `#[facet(rename_all = "...")]` convention (if any),
+/// or otherwise under one of the common serde-style `rename_all` case
+/// conventions applied to `field_name` (camelCase, PascalCase, kebab-case,
+/// SCREAMING_SNAKE_CASE).
+///
+/// The automatic multi-convention fallback lets idiomatic `snake_case` Rust
+/// fields round-trip JSON that uses any of these conventions without
+/// requiring an explicit per-field `rename`; the `rename_all` hint takes
+/// precedence when the container declares one, so serialization and
+/// deserialization agree on exactly one convention instead of silently
+/// accepting near-matches from other conventions too.
+fn field_name_matches(field_name: &'static str, key: &str, rename_all: Option<&str>) -> bool {
+    if field_name == key {
+        return true;
+    }
+
+    if let Some(case) = rename_all {
+        return apply_rename_all(field_name, case) == key;
+    }
+
+    let mut words = ident_words(field_name);
+    let Some(first) = words.next() else {
+        return false;
+    };
+    let rest: Vec<&str> = words.collect();
+
+    // camelCase / PascalCase
+    let mut camel = String::new();
+    camel.push_str(first);
+    let mut pascal = String::new();
+    pascal.push_str(&capitalize(first));
+    for word in &rest {
+        camel.push_str(&capitalize(word));
+        pascal.push_str(&capitalize(word));
+    }
+    if camel == key || pascal == key {
+        return true;
+    }
+
+    // kebab-case
+    let kebab: String = core::iter::once(first)
+        .chain(rest.iter().copied())
+        .collect::<Vec<_>>()
+        .join("-");
+    if kebab == key {
+        return true;
+    }
+
+    // SCREAMING_SNAKE_CASE
+    let screaming = field_name.to_ascii_uppercase();
+    screaming == key
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
 // ============================================================================
 // Error Types
 // ============================================================================
 
+/// One segment of the location path leading to a [`JsonError`]: either a
+/// struct/map field name or an array/list index, mirroring a JSON Pointer
+/// (RFC 6901) reference token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathComponent {
+    /// A struct or map field, by name
+    Field(&'static str),
+    /// An array, list, set, or tuple element, by index
+    Index(usize),
+}
+
+impl Display for PathComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathComponent::Field(name) => write!(f, "{name}"),
+            PathComponent::Index(idx) => write!(f, "{idx}"),
+        }
+    }
+}
+
+/// Render a path as a JSON Pointer string, e.g. `/users/3/name`.
+fn render_pointer(path: &[PathComponent]) -> String {
+    let mut s = String::new();
+    for component in path {
+        s.push('/');
+        s.push_str(&component.to_string());
+    }
+    s
+}
+
 /// Error type for JSON deserialization.
 #[derive(Debug)]
 pub struct JsonError {
@@ -48,11 +224,18 @@ pub struct JsonError {
     pub span: Option<Span>,
     /// The source input (for diagnostics)
     pub source_code: Option<String>,
+    /// Path from the document root to where the error occurred, rendered as
+    /// a JSON Pointer (e.g. `/users/3/name`) in `Display` and diagnostics.
+    pub path: Vec<PathComponent>,
 }
 
 impl Display for JsonError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.kind)
+        write!(f, "{}", self.kind)?;
+        if !self.path.is_empty() {
+            write!(f, " at `{}`", render_pointer(&self.path))?;
+        }
+        Ok(())
     }
 }
 
@@ -69,7 +252,30 @@ impl miette::Diagnostic for JsonError {
             .map(|s| s as &dyn miette::SourceCode)
     }
 
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        if self.path.is_empty() {
+            None
+        } else {
+            Some(Box::new(format!(
+                "at JSON pointer `{}`",
+                render_pointer(&self.path)
+            )))
+        }
+    }
+
+    fn related(&self) -> Option<Box<dyn Iterator<Item = &dyn miette::Diagnostic> + '_>> {
+        if let JsonErrorKind::Multiple(errors) = &self.kind {
+            return Some(Box::new(errors.iter().map(|e| e as &dyn miette::Diagnostic)));
+        }
+        None
+    }
+
     fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        // Multiple errors report themselves through `related()` instead
+        if let JsonErrorKind::Multiple(_) = &self.kind {
+            return None;
+        }
+
         // Handle MissingField with multiple spans
         if let JsonErrorKind::MissingField {
             field,
@@ -115,6 +321,7 @@ impl JsonError {
             kind,
             span: Some(span),
             source_code: None,
+            path: Vec::new(),
         }
     }
 
@@ -124,6 +331,7 @@ impl JsonError {
             kind,
             span: None,
             source_code: None,
+            path: Vec::new(),
         }
     }
 
@@ -174,6 +382,16 @@ pub enum JsonErrorKind {
         /// Suggested field name (if similar to an expected field)
         suggestion: Option<&'static str>,
     },
+    /// Unknown enum variant name (from an externally/internally/adjacently
+    /// tagged enum's tag value)
+    UnknownVariant {
+        /// The unknown variant name
+        variant: String,
+        /// List of valid variant names
+        expected: Vec<&'static str>,
+        /// Suggested variant name (if similar to an expected variant)
+        suggestion: Option<&'static str>,
+    },
     /// Missing required field
     MissingField {
         /// The name of the missing field
@@ -206,6 +424,18 @@ pub enum JsonErrorKind {
     InvalidUtf8,
     /// Solver error (for flattened types)
     Solver(String),
+    /// Multiple recoverable errors collected during error-accumulation mode
+    Multiple(Vec<JsonError>),
+    /// Nesting depth exceeded the configured limit
+    DepthLimitExceeded {
+        /// The configured maximum nesting depth
+        max_depth: usize,
+    },
+    /// A requested option isn't implemented by this build's tokenizer
+    UnsupportedOption {
+        /// Name of the option that was requested
+        option: &'static str,
+    },
 }
 
 impl Display for JsonErrorKind {
@@ -238,6 +468,20 @@ impl Display for JsonErrorKind {
                 }
                 Ok(())
             }
+            JsonErrorKind::UnknownVariant {
+                variant,
+                expected,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "unknown variant `{variant}`, expected one of: {expected:?}"
+                )?;
+                if let Some(suggested) = suggestion {
+                    write!(f, " (did you mean `{suggested}`?)")?;
+                }
+                Ok(())
+            }
             JsonErrorKind::MissingField { field, .. } => {
                 write!(f, "missing required field `{field}`")
             }
@@ -253,6 +497,15 @@ impl Display for JsonErrorKind {
             }
             JsonErrorKind::InvalidUtf8 => write!(f, "invalid UTF-8 sequence"),
             JsonErrorKind::Solver(msg) => write!(f, "solver error: {msg}"),
+            JsonErrorKind::Multiple(errors) => {
+                write!(f, "{} errors occurred during deserialization", errors.len())
+            }
+            JsonErrorKind::DepthLimitExceeded { max_depth } => {
+                write!(f, "nesting depth exceeded the limit of {max_depth}")
+            }
+            JsonErrorKind::UnsupportedOption { option } => {
+                write!(f, "the `{option}` option is not supported by this build")
+            }
         }
     }
 }
@@ -267,6 +520,7 @@ impl JsonErrorKind {
             JsonErrorKind::UnexpectedEof { .. } => "json::unexpected_eof",
             JsonErrorKind::TypeMismatch { .. } => "json::type_mismatch",
             JsonErrorKind::UnknownField { .. } => "json::unknown_field",
+            JsonErrorKind::UnknownVariant { .. } => "json::unknown_variant",
             JsonErrorKind::MissingField { .. } => "json::missing_field",
             JsonErrorKind::InvalidValue { .. } => "json::invalid_value",
             JsonErrorKind::Reflect(_) => "json::reflect",
@@ -274,6 +528,9 @@ impl JsonErrorKind {
             JsonErrorKind::DuplicateKey { .. } => "json::duplicate_key",
             JsonErrorKind::InvalidUtf8 => "json::invalid_utf8",
             JsonErrorKind::Solver(_) => "json::solver",
+            JsonErrorKind::Multiple(_) => "json::multiple",
+            JsonErrorKind::DepthLimitExceeded { .. } => "json::depth_limit_exceeded",
+            JsonErrorKind::UnsupportedOption { .. } => "json::unsupported_option",
         }
     }
 
@@ -315,6 +572,15 @@ impl JsonErrorKind {
                     format!("unknown field '{field}'")
                 }
             }
+            JsonErrorKind::UnknownVariant {
+                variant, suggestion, ..
+            } => {
+                if let Some(suggested) = suggestion {
+                    format!("unknown variant '{variant}' - did you mean '{suggested}'?")
+                } else {
+                    format!("unknown variant '{variant}'")
+                }
+            }
             JsonErrorKind::MissingField { field, .. } => format!("missing field '{field}'"),
             JsonErrorKind::InvalidValue { .. } => "invalid value".into(),
             JsonErrorKind::Reflect(_) => "reflection error".into(),
@@ -324,8 +590,103 @@ impl JsonErrorKind {
             JsonErrorKind::DuplicateKey { key } => format!("duplicate key '{key}'"),
             JsonErrorKind::InvalidUtf8 => "invalid UTF-8".into(),
             JsonErrorKind::Solver(_) => "solver error".into(),
+            JsonErrorKind::Multiple(errors) => format!("{} errors", errors.len()),
+            JsonErrorKind::DepthLimitExceeded { max_depth } => {
+                format!("exceeded max depth of {max_depth}")
+            }
+            JsonErrorKind::UnsupportedOption { option } => format!("`{option}` not supported"),
+        }
+    }
+}
+
+/// Converts a byte offset into a 1-based `(line, column)` pair within `src`,
+/// for [`render_diagnostic_json`]'s `spans` entries.
+fn line_col(src: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(src.len());
+    let mut line = 1;
+    let mut column = 1;
+    for ch in src[..offset].chars() {
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// Renders a [`JsonError`] as a structured, machine-readable JSON
+/// diagnostic record, mirroring rustc's `--error-format=json`: a `level`,
+/// `message`, `code`, a `spans` array (byte offset, length, 1-based
+/// line/column, and a label), an optional `help`, and a `rendered` field
+/// carrying the same ANSI graphical output `GraphicalReportHandler` would
+/// print for a human. This lets editors/LSP front-ends consume facet-json
+/// failures programmatically while the pretty form stays available via
+/// `rendered`. `Multiple` errors (from error-accumulation mode) nest their
+/// individual errors under `children`.
+pub fn render_diagnostic_json(err: &JsonError) -> String {
+    let mut buf = Vec::new();
+    write_diagnostic_json(err, &mut buf);
+    String::from_utf8(buf).expect("diagnostic JSON is valid UTF-8")
+}
+
+fn write_diagnostic_json(err: &JsonError, buf: &mut Vec<u8>) {
+    buf.extend_from_slice(b"{\"level\":\"error\",\"message\":");
+    crate::write_json_string(buf, &err.kind.to_string());
+
+    buf.extend_from_slice(b",\"code\":");
+    crate::write_json_string(buf, err.kind.code());
+
+    buf.extend_from_slice(b",\"spans\":[");
+    if let Some(span) = err.span {
+        let (line, column) = err
+            .source_code
+            .as_deref()
+            .map(|src| line_col(src, span.start))
+            .unwrap_or((1, 1));
+        buf.extend_from_slice(b"{\"offset\":");
+        buf.extend_from_slice(itoa::Buffer::new().format(span.start).as_bytes());
+        buf.extend_from_slice(b",\"length\":");
+        buf.extend_from_slice(itoa::Buffer::new().format(span.len).as_bytes());
+        buf.extend_from_slice(b",\"line\":");
+        buf.extend_from_slice(itoa::Buffer::new().format(line).as_bytes());
+        buf.extend_from_slice(b",\"column\":");
+        buf.extend_from_slice(itoa::Buffer::new().format(column).as_bytes());
+        buf.extend_from_slice(b",\"label\":");
+        crate::write_json_string(buf, &err.kind.label());
+        buf.push(b'}');
+    }
+    buf.push(b']');
+
+    let diag: &dyn miette::Diagnostic = err;
+    buf.extend_from_slice(b",\"help\":");
+    match diag.help() {
+        Some(help) => crate::write_json_string(buf, &help.to_string()),
+        None => buf.extend_from_slice(b"null"),
+    }
+
+    buf.extend_from_slice(b",\"rendered\":");
+    let mut rendered = String::new();
+    let handler = miette::GraphicalReportHandler::new();
+    if handler.render_report(&mut rendered, diag).is_ok() {
+        crate::write_json_string(buf, &rendered);
+    } else {
+        buf.extend_from_slice(b"null");
+    }
+
+    if let JsonErrorKind::Multiple(children) = &err.kind {
+        buf.extend_from_slice(b",\"children\":[");
+        for (i, child) in children.iter().enumerate() {
+            if i > 0 {
+                buf.push(b',');
+            }
+            write_diagnostic_json(child, buf);
         }
+        buf.push(b']');
     }
+
+    buf.push(b'}');
 }
 
 impl From<TokenError> for JsonError {
@@ -334,6 +695,7 @@ impl From<TokenError> for JsonError {
             kind: JsonErrorKind::Token(err.kind),
             span: Some(err.span),
             source_code: None,
+            path: Vec::new(),
         }
     }
 }
@@ -344,6 +706,7 @@ impl From<ReflectError> for JsonError {
             kind: JsonErrorKind::Reflect(err),
             span: None,
             source_code: None,
+            path: Vec::new(),
         }
     }
 }
@@ -360,6 +723,82 @@ pub type Result<T> = core::result::Result<T, JsonError>;
 /// Returns `true` if the shape is a struct with exactly two fields:
 /// - `value` (the inner value)
 /// - `span` (for storing source location)
+/// Check if a shape is the dynamic [`JsonValue`] type, which gets parsed
+/// directly rather than driven by struct/enum reflection.
+fn is_json_value_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "JsonValue"
+}
+
+/// Check if a shape is the [`crate::raw::JsonRaw`] passthrough type, which
+/// captures the next value's exact source text instead of parsing it.
+fn is_json_raw_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "JsonRaw"
+}
+
+/// Check if a shape is the dynamic [`crate::value::SpannedJsonValue`] type,
+/// which gets parsed directly (like [`JsonValue`]) rather than driven by
+/// struct/enum reflection, but records a [`Span`] for every array element
+/// and object member as it goes.
+fn is_spanned_json_value_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "SpannedJsonValue"
+}
+
+/// Check if a shape is the [`crate::number::JsonNumber`] arbitrary-precision
+/// passthrough type, which captures the next number's exact source text
+/// instead of parsing it into a native integer/float type.
+fn is_json_number_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "JsonNumber"
+}
+
+/// Resolves a JSON externally-tagged-enum discriminant string against the
+/// enum's variant names under its container-level
+/// `#[facet(rename_all = "...")]` convention, if it declares one.
+///
+/// Returns `None` (leaving the caller to pass `key` through to
+/// [`facet_reflect::Partial::select_variant_named`] unchanged, so its own
+/// "unknown variant" error still fires) when the shape declares no explicit
+/// convention - this only kicks in for the opt-in case, not the automatic
+/// multi-convention guessing [`field_name_matches`] does for struct fields.
+fn resolve_variant_name(shape: &Shape, key: &str, rename_all: Option<&str>) -> Option<&'static str> {
+    rename_all?;
+    if let Type::User(UserType::Enum(e)) = &shape.ty {
+        for v in e.variants {
+            if field_name_matches(v.name, key, rename_all) {
+                return Some(v.name);
+            }
+        }
+    }
+    None
+}
+
+/// Selects an enum variant by name, enriching the reflection-level "unknown
+/// variant" error with a ranked "did you mean" suggestion
+/// (`JsonErrorKind::UnknownVariant`) when a close match exists among the
+/// enum's declared variants - the enum-variant counterpart of the
+/// `find_similar_field` suggestion already attached to unknown struct fields.
+fn select_variant_with_suggestion<'input>(
+    wip: &mut Partial<'input>,
+    variant_name: &str,
+    span: Span,
+) -> Result<()> {
+    if wip.select_variant_named(variant_name).is_ok() {
+        return Ok(());
+    }
+    let expected: Vec<&'static str> = match &wip.shape().ty {
+        Type::User(UserType::Enum(e)) => e.variants.iter().map(|v| v.name).collect(),
+        _ => Vec::new(),
+    };
+    let suggestion = find_similar_field(variant_name, &expected);
+    Err(JsonError::new(
+        JsonErrorKind::UnknownVariant {
+            variant: variant_name.to_string(),
+            expected,
+            suggestion,
+        },
+        span,
+    ))
+}
+
 fn is_spanned_shape(shape: &Shape) -> bool {
     if let Type::User(UserType::Struct(struct_def)) = &shape.ty {
         if struct_def.fields.len() == 2 {
@@ -371,25 +810,396 @@ fn is_spanned_shape(shape: &Shape) -> bool {
     false
 }
 
+/// Check if a shape is the dynamic [`JsonValue`] type, which gets parsed
+/// directly rather than driven by struct/enum reflection.
+fn is_json_value_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "JsonValue"
+}
+
+/// Check if a shape is the [`crate::raw::JsonRaw`] passthrough type, which
+/// captures the next value's exact source text instead of parsing it.
+fn is_json_raw_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "JsonRaw"
+}
+
+/// Check if a shape is the dynamic [`crate::value::SpannedJsonValue`] type,
+/// which gets parsed directly (like [`JsonValue`]) rather than driven by
+/// struct/enum reflection, but records a [`Span`] for every array element
+/// and object member as it goes.
+fn is_spanned_json_value_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "SpannedJsonValue"
+}
+
+/// Check if a shape is the [`crate::number::JsonNumber`] arbitrary-precision
+/// passthrough type, which captures the next number's exact source text
+/// instead of parsing it into a native integer/float type.
+fn is_json_number_shape(shape: &Shape) -> bool {
+    shape.type_identifier == "JsonNumber"
+}
+
+/// Resolves a JSON externally-tagged-enum discriminant string against the
+/// enum's variant names under its container-level
+/// `#[facet(rename_all = "...")]` convention, if it declares one.
+///
+/// Returns `None` (leaving the caller to pass `key` through to
+/// [`facet_reflect::Partial::select_variant_named`] unchanged, so its own
+/// "unknown variant" error still fires) when the shape declares no explicit
+/// convention - this only kicks in for the opt-in case, not the automatic
+/// multi-convention guessing [`field_name_matches`] does for struct fields.
+fn resolve_variant_name(shape: &Shape, key: &str, rename_all: Option<&str>) -> Option<&'static str> {
+    rename_all?;
+    if let Type::User(UserType::Enum(e)) = &shape.ty {
+        for v in e.variants {
+            if field_name_matches(v.name, key, rename_all) {
+                return Some(v.name);
+            }
+        }
+    }
+    None
+}
+
+/// Selects an enum variant by name, enriching the reflection-level "unknown
+/// variant" error with a ranked "did you mean" suggestion
+/// (`JsonErrorKind::UnknownVariant`) when a close match exists among the
+/// enum's declared variants - the enum-variant counterpart of the
+/// `find_similar_field` suggestion already attached to unknown struct fields.
+fn select_variant_with_suggestion<'input>(
+    wip: &mut Partial<'input>,
+    variant_name: &str,
+    span: Span,
+) -> Result<()> {
+    if wip.select_variant_named(variant_name).is_ok() {
+        return Ok(());
+    }
+    let expected: Vec<&'static str> = match &wip.shape().ty {
+        Type::User(UserType::Enum(e)) => e.variants.iter().map(|v| v.name).collect(),
+        _ => Vec::new(),
+    };
+    let suggestion = find_similar_field(variant_name, &expected);
+    Err(JsonError::new(
+        JsonErrorKind::UnknownVariant {
+            variant: variant_name.to_string(),
+            expected,
+            suggestion,
+        },
+        span,
+    ))
+}
+
+/// Check if a shape is a `Vec<u8>`/`[u8; N]` byte sequence, eligible for
+/// [`DeserializerOptions::byte_encoding`]'s base64/hex string form.
+fn is_u8_sequence_shape(shape: &Shape) -> bool {
+    match &shape.def {
+        Def::List(ld) => ld.t().is_type::<u8>(),
+        Def::Array(ad) => ad.t().is_type::<u8>(),
+        _ => false,
+    }
+}
+
+/// A decode error with the byte offset into the decoded string (not the
+/// surrounding JSON source) where the problem was found, so callers can
+/// report something more useful than "somewhere in this string".
+type DecodeError = (&'static str, usize);
+
+/// Decodes standard base64 (RFC 4648 §4, with or without `=` padding).
+fn decode_base64(s: &str) -> core::result::Result<Vec<u8>, DecodeError> {
+    decode_base64_with(s, false)
+}
+
+/// Decodes URL-safe base64 (RFC 4648 §5, with or without `=` padding).
+fn decode_base64_url(s: &str) -> core::result::Result<Vec<u8>, DecodeError> {
+    decode_base64_with(s, true)
+}
+
+fn decode_base64_with(s: &str, url_safe: bool) -> core::result::Result<Vec<u8>, DecodeError> {
+    fn value(byte: u8, url_safe: bool) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' if !url_safe => Some(62),
+            b'/' if !url_safe => Some(63),
+            b'-' if url_safe => Some(62),
+            b'_' if url_safe => Some(63),
+            _ => None,
+        }
+    }
+
+    let trimmed = s.trim_end_matches('=');
+    let bytes = trimmed.as_bytes();
+    if bytes.len() % 4 == 1 {
+        // A trailing chunk of exactly one character can't decode to a whole
+        // byte (it only carries 6 bits), so without this check it would
+        // silently emit a byte built from a placeholder zero instead of
+        // erroring - reject it the same way `decode_hex` rejects an odd
+        // number of hex digits.
+        return Err((
+            "base64 string has an invalid length",
+            bytes.len() - 1,
+        ));
+    }
+    let mut out = Vec::with_capacity(bytes.len() * 3 / 4);
+    for (chunk_idx, chunk) in bytes.chunks(4).enumerate() {
+        let mut v = [0u8; 4];
+        for (i, &b) in chunk.iter().enumerate() {
+            v[i] = value(b, url_safe)
+                .ok_or(("invalid base64 character", chunk_idx * 4 + i))?;
+        }
+        out.push((v[0] << 2) | (v[1] >> 4));
+        if chunk.len() > 2 {
+            out.push((v[1] << 4) | (v[2] >> 2));
+        }
+        if chunk.len() > 3 {
+            out.push((v[2] << 6) | v[3]);
+        }
+    }
+    Ok(out)
+}
+
+/// Decodes lowercase or uppercase hexadecimal, two characters per byte.
+fn decode_hex(s: &str) -> core::result::Result<Vec<u8>, DecodeError> {
+    let bytes = s.as_bytes();
+    if bytes.len() % 2 != 0 {
+        return Err(("hex string must have an even number of characters", bytes.len()));
+    }
+    fn nibble(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+    bytes
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            let hi = nibble(pair[0]).ok_or(("invalid hex character", i * 2))?;
+            let lo = nibble(pair[1]).ok_or(("invalid hex character", i * 2 + 1))?;
+            Ok((hi << 4) | lo)
+        })
+        .collect()
+}
+
 // ============================================================================
 // Deserializer
 // ============================================================================
 
+/// The default nesting depth limit used when no [`DeserializerOptions`] are
+/// given explicitly, chosen to comfortably fit within the default thread
+/// stack size while still rejecting pathological/adversarial input.
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// Options controlling how a [`JsonDeserializer`] parses its input.
+///
+/// Construct with [`DeserializerOptions::new`] (or [`Default::default`]) and
+/// chain the builder methods, then pass the result to
+/// [`from_slice_with_options`] / [`from_str_with_options`].
+#[derive(Debug, Clone, Copy)]
+pub struct DeserializerOptions {
+    max_depth: usize,
+    json5: bool,
+    deny_unknown_fields: bool,
+    byte_encoding: ByteEncoding,
+    duplicate_keys: DuplicateKeyPolicy,
+}
+
+/// What to do when a JSON object contains the same struct field key more
+/// than once - see [`DeserializerOptions::duplicate_keys`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeyPolicy {
+    /// Keep the first occurrence, skipping the value of every subsequent
+    /// one without deserializing it.
+    FirstWins,
+    /// Keep the last occurrence, overwriting the field each time it
+    /// reappears. This is the implicit behavior this crate has always had.
+    #[default]
+    LastWins,
+    /// Reject the object with [`JsonErrorKind::DuplicateKey`] as soon as a
+    /// key repeats (or, in error-accumulation mode, record it and keep the
+    /// first occurrence while parsing the rest of the object).
+    Error,
+}
+
+impl DeserializerOptions {
+    /// Create a new set of options with the defaults (`max_depth` of
+    /// [`DEFAULT_MAX_DEPTH`], JSON5 relaxed syntax disabled).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum recursion depth of the deserializer.
+    ///
+    /// Every value the deserializer descends into counts one level - the
+    /// top-level value and each nested struct, enum, list, map, set, tuple,
+    /// `Option` and smart-pointer layer - and deserialization fails with
+    /// [`JsonErrorKind::DepthLimitExceeded`] once more than this many levels
+    /// nest, which guards against stack overflow from adversarial or
+    /// accidentally-recursive input. Values parsed into [`JsonValue`] or
+    /// [`crate::value::SpannedJsonValue`] are not counted, and values
+    /// replayed by a sub-deserializer (flattened fields, catch-all maps,
+    /// adjacently/internally tagged and untagged enums) count from zero
+    /// against [`DEFAULT_MAX_DEPTH`] rather than this setting.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Opt into JSON5/JSONC's relaxed syntax (comments, trailing commas,
+    /// unquoted object keys, single-quoted strings, hex literals).
+    ///
+    /// Of these, trailing commas are already accepted by the
+    /// list/object/struct parsing loops without this option - they check
+    /// for the closing bracket/brace before requiring another element, so a
+    /// trailing comma just falls out of that loop structure rather than
+    /// needing dedicated support. Fixed-size arrays, tuples, and values
+    /// parsed into [`JsonValue`] or [`crate::value::SpannedJsonValue`]
+    /// still reject a trailing comma. Comments, unquoted
+    /// keys, single-quoted strings, and hex literals are lexer-level
+    /// features, and this build's tokenizer only lexes strict JSON, so
+    /// enabling this option currently fails fast with
+    /// [`JsonErrorKind::UnsupportedOption`] rather than silently parsing as
+    /// strict JSON.
+    pub fn json5(mut self, enabled: bool) -> Self {
+        self.json5 = enabled;
+        self
+    }
+
+    /// Reject any JSON object member that no field - named, flattened, or
+    /// catch-all - of its target type would consume, regardless of whether
+    /// the type itself carries `#[facet(deny_unknown_fields)]`.
+    ///
+    /// This is the same check `#[facet(deny_unknown_fields)]` enables per
+    /// type, applied to every object this deserializer parses directly; the
+    /// two compose (a type can still opt in locally even when this is left
+    /// `false`). Values replayed by a sub-deserializer from a buffered
+    /// position - the field values of a struct with flattened or catch-all
+    /// fields, and the content of adjacently/internally tagged and untagged
+    /// enum variants - are parsed without this setting, and an internally
+    /// tagged variant's buffered fields ignore keys that match no field.
+    pub fn deny_unknown_fields(mut self, enabled: bool) -> Self {
+        self.deny_unknown_fields = enabled;
+        self
+    }
+
+    /// Accepts byte sequences (`Vec<u8>`, `[u8; N]`) encoded as a
+    /// base64 or hex JSON string, in addition to the always-accepted array
+    /// of numbers - see [`ByteEncoding`]. Only the chosen encoding's string
+    /// form is accepted; a document using a different encoding than
+    /// configured here fails to parse. Defaults to [`ByteEncoding::Array`],
+    /// which leaves only the array-of-numbers form accepted.
+    pub fn byte_encoding(mut self, encoding: ByteEncoding) -> Self {
+        self.byte_encoding = encoding;
+        self
+    }
+
+    /// Controls what happens when a JSON object repeats the same key for a
+    /// struct field - see [`DuplicateKeyPolicy`]. Defaults to
+    /// [`DuplicateKeyPolicy::LastWins`], matching this crate's historical
+    /// behavior.
+    pub fn duplicate_keys(mut self, policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_keys = policy;
+        self
+    }
+}
+
+impl Default for DeserializerOptions {
+    fn default() -> Self {
+        DeserializerOptions {
+            max_depth: DEFAULT_MAX_DEPTH,
+            json5: false,
+            deny_unknown_fields: false,
+            byte_encoding: ByteEncoding::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        }
+    }
+}
+
 /// JSON deserializer using recursive descent.
 pub struct JsonDeserializer<'input> {
     input: &'input [u8],
     tokenizer: Tokenizer<'input>,
     /// Peeked token (for lookahead)
     peeked: Option<Spanned<Token<'input>>>,
+    /// When `true`, recoverable errors (unknown/missing fields, type mismatches,
+    /// out-of-range numbers, duplicate keys) are pushed into `errors` and
+    /// deserialization resynchronizes and continues instead of aborting.
+    collect_errors: bool,
+    /// Errors collected so far when `collect_errors` is enabled.
+    errors: Vec<JsonError>,
+    /// Stack of field names / indices leading to the value currently being
+    /// deserialized, snapshotted into any `JsonError` built while it's
+    /// non-empty so errors can report a JSON-pointer-style location.
+    path_stack: Vec<PathComponent>,
+    /// Current recursion depth, incremented/decremented around every
+    /// `deserialize_into` call and checked against `max_depth`.
+    depth: usize,
+    /// Maximum allowed recursion depth, from `DeserializerOptions`.
+    max_depth: usize,
+    /// Whether JSON5 relaxed syntax was requested; see [`DeserializerOptions::json5`].
+    json5: bool,
+    /// When `true`, zero-copy `&str`/`&[u8]` targets are rejected with a
+    /// clear [`JsonErrorKind::InvalidValue`] instead of borrowing from
+    /// `input`, because `input` is a buffer owned by the deserializer itself
+    /// (e.g. read from an [`std::io::Read`] in [`from_reader`]) rather than
+    /// data the caller can keep alive for `'input`.
+    reject_borrows: bool,
+    /// Whether [`DeserializerOptions::deny_unknown_fields`] was requested,
+    /// making every object strict regardless of its own
+    /// `#[facet(deny_unknown_fields)]` attribute.
+    deny_unknown_fields: bool,
+    /// Which string encoding, if any, is accepted for byte sequences in
+    /// addition to the array-of-numbers form; see
+    /// [`DeserializerOptions::byte_encoding`].
+    byte_encoding: ByteEncoding,
+    /// What to do when a struct field's key repeats within the same object;
+    /// see [`DeserializerOptions::duplicate_keys`].
+    duplicate_keys: DuplicateKeyPolicy,
 }
 
 impl<'input> JsonDeserializer<'input> {
     /// Create a new deserializer for the given input.
     pub fn new(input: &'input [u8]) -> Self {
+        Self::with_options(input, DeserializerOptions::default())
+    }
+
+    /// Create a new deserializer for the given input with custom options.
+    pub fn with_options(input: &'input [u8], options: DeserializerOptions) -> Self {
         JsonDeserializer {
             input,
             tokenizer: Tokenizer::new(input),
             peeked: None,
+            collect_errors: false,
+            errors: Vec::new(),
+            path_stack: Vec::new(),
+            depth: 0,
+            max_depth: options.max_depth,
+            json5: options.json5,
+            reject_borrows: false,
+            deny_unknown_fields: options.deny_unknown_fields,
+            byte_encoding: options.byte_encoding,
+            duplicate_keys: options.duplicate_keys,
+        }
+    }
+
+    /// Create a deserializer that accumulates recoverable errors instead of
+    /// bailing on the first one, returning a `JsonErrorKind::Multiple` with
+    /// every problem found in a single pass.
+    pub fn new_collecting(input: &'input [u8]) -> Self {
+        JsonDeserializer {
+            input,
+            tokenizer: Tokenizer::new(input),
+            peeked: None,
+            collect_errors: true,
+            errors: Vec::new(),
+            path_stack: Vec::new(),
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            json5: false,
+            reject_borrows: false,
+            deny_unknown_fields: false,
+            byte_encoding: ByteEncoding::default(),
+            duplicate_keys: DuplicateKeyPolicy::Error,
         }
     }
 
@@ -400,6 +1210,27 @@ impl<'input> JsonDeserializer<'input> {
             input,
             tokenizer: Tokenizer::new(&input[offset..]),
             peeked: None,
+            collect_errors: false,
+            errors: Vec::new(),
+            path_stack: Vec::new(),
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            json5: false,
+            reject_borrows: false,
+            deny_unknown_fields: false,
+            byte_encoding: ByteEncoding::default(),
+            duplicate_keys: DuplicateKeyPolicy::default(),
+        }
+    }
+
+    /// Record a recoverable error: push it onto the collector and resume if
+    /// error-accumulation mode is enabled, otherwise return it immediately.
+    fn record_error(&mut self, err: JsonError) -> Result<()> {
+        if self.collect_errors {
+            self.errors.push(err);
+            Ok(())
+        } else {
+            Err(err)
         }
     }
 
@@ -411,6 +1242,23 @@ impl<'input> JsonDeserializer<'input> {
         Ok(self.peeked.as_ref().unwrap())
     }
 
+    /// Create an iterator that deserializes a newline-delimited stream of
+    /// JSON documents (NDJSON / JSON Lines), yielding one `Result<T>` per
+    /// top-level value.
+    ///
+    /// Each call to `next()` parses exactly one value and stops at its
+    /// boundary; whitespace and newlines between values are skipped before
+    /// parsing the next one. A parse error on one value does not poison the
+    /// rest of the stream - the iterator resynchronizes at the next newline
+    /// and resumes from there.
+    pub fn iter_lines<T: Facet<'input>>(input: &'input [u8]) -> Lines<'input, T> {
+        Lines {
+            input,
+            offset: 0,
+            _marker: PhantomData,
+        }
+    }
+
     /// Consume and return the next token.
     fn next(&mut self) -> Result<Spanned<Token<'input>>> {
         if let Some(token) = self.peeked.take() {
@@ -434,6 +1282,7 @@ impl<'input> JsonDeserializer<'input> {
                         },
                         span: e.span,
                         source_code: e.source_code,
+                        path: e.path,
                     })
                 } else {
                     Err(e)
@@ -450,6 +1299,40 @@ impl<'input> JsonDeserializer<'input> {
         Ok(token)
     }
 
+    /// After a recoverable error partway through an array/object element (as
+    /// opposed to a scalar leaf, which always fails after consuming exactly
+    /// one token), skip forward to resynchronize with the enclosing
+    /// container: consume tokens - tracking nested bracket/brace depth so a
+    /// `,`/`}`/`]` belonging to something the failed value itself opened
+    /// doesn't get mistaken for the enclosing container's boundary - until a
+    /// `,`, `}`, or `]` at the *current* depth is found. A comma is consumed
+    /// (there's more to parse); a closing `}`/`]` is left in place for the
+    /// caller's own end-of-container check.
+    fn resync_after_error(&mut self) -> Result<()> {
+        let mut depth: usize = 0;
+        loop {
+            let token = self.peek()?;
+            match token.node {
+                Token::Comma if depth == 0 => {
+                    self.next()?;
+                    return Ok(());
+                }
+                Token::RBrace | Token::RBracket if depth == 0 => return Ok(()),
+                Token::LBrace | Token::LBracket => {
+                    depth += 1;
+                    self.next()?;
+                }
+                Token::RBrace | Token::RBracket => {
+                    depth -= 1;
+                    self.next()?;
+                }
+                _ => {
+                    self.next()?;
+                }
+            }
+        }
+    }
+
     /// Skip a JSON value (for unknown fields).
     fn skip_value(&mut self) -> Result<Span> {
         let token = self.next()?;
@@ -568,11 +1451,90 @@ impl<'input> JsonDeserializer<'input> {
             .any(|f| f.flags.contains(FieldFlags::FLATTEN))
     }
 
+    /// Find a flattened map field (e.g. `#[facet(flatten)] extra:
+    /// HashMap<String, JsonValue>`) that should absorb any JSON members no
+    /// other named or flattened field claims.
+    fn find_flatten_map_field(
+        struct_def: &'static facet_core::StructType,
+    ) -> Option<&'static facet_core::Field> {
+        struct_def
+            .fields
+            .iter()
+            .find(|f| f.flags.contains(FieldFlags::FLATTEN) && matches!(f.shape().def, Def::Map(_)))
+    }
+
     /// Main deserialization entry point - deserialize into a Partial.
+    ///
+    /// Wraps [`Self::deserialize_into_inner`] to snapshot the current
+    /// [`PathComponent`] stack into any error it returns, so the JSON Pointer
+    /// location is taken from the deepest call that actually produced the
+    /// error rather than being overwritten as it bubbles back up.
+    ///
+    /// This is also the single recursion chokepoint: `deserialize_map`,
+    /// `deserialize_array`, `deserialize_set`, `deserialize_tuple`, and the
+    /// slice branch of `deserialize_pointer` all recurse by calling back into
+    /// this method for each element/value, so bumping and checking `depth`
+    /// here bounds all of them without needing a separate guard in each one.
+    /// `self.depth` is decremented unconditionally after the inner call
+    /// returns (whether `Ok` or `Err`), so an early `?`-return deep in a
+    /// nested container can never leave it permanently incremented.
     pub fn deserialize_into(&mut self, wip: &mut Partial<'input>) -> Result<()> {
+        if self.depth >= self.max_depth {
+            return Err(JsonError::new(
+                JsonErrorKind::DepthLimitExceeded {
+                    max_depth: self.max_depth,
+                },
+                self.peek().map(|t| t.span).unwrap_or_default(),
+            ));
+        }
+        self.depth += 1;
+        let result = self.deserialize_into_inner(wip);
+        self.depth -= 1;
+        result.map_err(|mut e| {
+            if e.path.is_empty() {
+                e.path = self.path_stack.clone();
+            }
+            e
+        })
+    }
+
+    fn deserialize_into_inner(&mut self, wip: &mut Partial<'input>) -> Result<()> {
         let shape = wip.shape();
         log::trace!("deserialize_into: shape={}", shape.type_identifier);
 
+        // Check for the dynamic JsonValue type first - it parses any JSON
+        // value directly instead of going through struct/enum reflection.
+        if is_json_value_shape(shape) {
+            let value = self.deserialize_json_value()?;
+            wip.set(value)?;
+            return Ok(());
+        }
+
+        // Same as JsonValue, but records a span for every array element and
+        // object member along the way.
+        if is_spanned_json_value_shape(shape) {
+            let value = self.deserialize_spanned_json_value()?;
+            wip.set(value)?;
+            return Ok(());
+        }
+
+        // Check for the raw-JSON passthrough type - captures exact source
+        // text instead of parsing it.
+        if is_json_raw_shape(shape) {
+            let value = self.deserialize_json_raw()?;
+            wip.set(value)?;
+            return Ok(());
+        }
+
+        // Check for the arbitrary-precision number passthrough type -
+        // captures exact source digits instead of parsing into a native
+        // integer/float that might not be able to represent them.
+        if is_json_number_shape(shape) {
+            let value = self.deserialize_json_number()?;
+            wip.set(value)?;
+            return Ok(());
+        }
+
         // Check for Spanned<T> wrapper first
         if is_spanned_shape(shape) {
             return self.deserialize_spanned(wip);
@@ -612,6 +1574,17 @@ impl<'input> JsonDeserializer<'input> {
             _ => {}
         }
 
+        // A byte sequence may be written as a base64/hex string instead of
+        // an array of numbers, if configured - see
+        // [`DeserializerOptions::byte_encoding`]. Only kicks in when the
+        // input is actually a string; an array still parses as one.
+        if self.byte_encoding != ByteEncoding::Array
+            && is_u8_sequence_shape(shape)
+            && matches!(self.peek()?.node, Token::String(_))
+        {
+            return self.deserialize_byte_string(wip);
+        }
+
         // Then check Def for containers and special types
         match &shape.def {
             Def::Scalar => self.deserialize_scalar(wip),
@@ -647,16 +1620,285 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
-    /// Deserialize a scalar value.
-    fn deserialize_scalar(&mut self, wip: &mut Partial<'input>) -> Result<()> {
-        let expected_type = wip.shape().type_identifier;
-        let token = self.next_expecting(expected_type)?;
-        log::trace!("deserialize_scalar: token={:?}", token.node);
+    /// Capture the next JSON value as raw, unparsed source text, bypassing
+    /// shape-driven recursive descent entirely.
+    ///
+    /// Reuses [`Self::skip_value`] for the structural skip (so strings with
+    /// embedded braces/brackets are handled correctly, since the tokenizer
+    /// already lexes a whole string as one token) and slices the exact byte
+    /// range out of `self.input` rather than re-serializing anything.
+    fn deserialize_json_raw(&mut self) -> Result<JsonRaw<'input>> {
+        let start = self.peek()?.span.start;
+        self.skip_value()?;
+        let end = self.peek()?.span.start;
+        let text = core::str::from_utf8(&self.input[start..end])
+            .map_err(|_| {
+                JsonError::new(
+                    JsonErrorKind::InvalidValue {
+                        message: "raw JSON capture was not valid UTF-8".into(),
+                    },
+                    Span::new(start, end - start),
+                )
+            })?
+            .trim_end();
+        Ok(JsonRaw::from_borrowed(text))
+    }
 
-        match token.node {
-            Token::String(s) => {
-                // Try parse_from_str first if the type supports it (e.g., chrono types)
-                if wip.shape().vtable.parse.is_some() {
+    /// Capture the next JSON number as its exact source text, instead of
+    /// parsing it into a native integer/float type.
+    ///
+    /// This never fails on magnitude or loses precision, since the tokenizer
+    /// has already isolated the number's span and we just slice it out of
+    /// `self.input` - the same approach [`Self::deserialize_json_raw`] uses
+    /// for whole values. A number so large the tokenizer itself can't lex it
+    /// as a single numeric token is out of reach here, same as everywhere
+    /// else in this deserializer.
+    fn deserialize_json_number(&mut self) -> Result<JsonNumber<'input>> {
+        let token = self.next()?;
+        if !matches!(
+            token.node,
+            Token::F64(_) | Token::I64(_) | Token::U64(_) | Token::I128(_) | Token::U128(_)
+        ) {
+            return Err(JsonError::new(
+                JsonErrorKind::UnexpectedToken {
+                    got: format!("{}", token.node),
+                    expected: "number",
+                },
+                token.span,
+            ));
+        }
+        let text = core::str::from_utf8(&self.input[token.span.start..token.span.end()])
+            .map_err(|_| {
+                JsonError::new(
+                    JsonErrorKind::InvalidValue {
+                        message: "number capture was not valid UTF-8".into(),
+                    },
+                    token.span,
+                )
+            })?;
+        Ok(JsonNumber::from_borrowed(text))
+    }
+
+    /// Parse the next JSON value into an untyped [`JsonValue`], bypassing the
+    /// shape-driven recursive descent entirely.
+    fn deserialize_json_value(&mut self) -> Result<JsonValue<'input>> {
+        let token = self.next()?;
+        match token.node {
+            Token::Null => Ok(JsonValue::Null),
+            Token::True => Ok(JsonValue::Bool(true)),
+            Token::False => Ok(JsonValue::Bool(false)),
+            Token::String(s) => Ok(JsonValue::String(s)),
+            Token::F64(n) => Ok(JsonValue::F64(n)),
+            Token::I64(n) => Ok(JsonValue::I64(n)),
+            Token::U64(n) => Ok(JsonValue::U64(n)),
+            Token::I128(n) => Ok(JsonValue::I128(n)),
+            Token::U128(n) => Ok(JsonValue::U128(n)),
+            Token::LBracket => {
+                let mut items = Vec::new();
+                if matches!(self.peek()?.node, Token::RBracket) {
+                    self.next()?;
+                    return Ok(JsonValue::Array(items));
+                }
+                loop {
+                    items.push(self.deserialize_json_value()?);
+                    let next = self.next()?;
+                    match next.node {
+                        Token::Comma => continue,
+                        Token::RBracket => break,
+                        _ => {
+                            return Err(JsonError::new(
+                                JsonErrorKind::UnexpectedToken {
+                                    got: format!("{}", next.node),
+                                    expected: "',' or ']'",
+                                },
+                                next.span,
+                            ));
+                        }
+                    }
+                }
+                Ok(JsonValue::Array(items))
+            }
+            Token::LBrace => {
+                let mut members = Vec::new();
+                if matches!(self.peek()?.node, Token::RBrace) {
+                    self.next()?;
+                    return Ok(JsonValue::Object(members));
+                }
+                loop {
+                    let key_token = self.next()?;
+                    let key = match key_token.node {
+                        Token::String(s) => s,
+                        _ => {
+                            return Err(JsonError::new(
+                                JsonErrorKind::UnexpectedToken {
+                                    got: format!("{}", key_token.node),
+                                    expected: "string key",
+                                },
+                                key_token.span,
+                            ));
+                        }
+                    };
+                    let colon = self.next()?;
+                    if !matches!(colon.node, Token::Colon) {
+                        return Err(JsonError::new(
+                            JsonErrorKind::UnexpectedToken {
+                                got: format!("{}", colon.node),
+                                expected: "':'",
+                            },
+                            colon.span,
+                        ));
+                    }
+                    members.push((key, self.deserialize_json_value()?));
+                    let next = self.next()?;
+                    match next.node {
+                        Token::Comma => continue,
+                        Token::RBrace => break,
+                        _ => {
+                            return Err(JsonError::new(
+                                JsonErrorKind::UnexpectedToken {
+                                    got: format!("{}", next.node),
+                                    expected: "',' or '}'",
+                                },
+                                next.span,
+                            ));
+                        }
+                    }
+                }
+                Ok(JsonValue::Object(members))
+            }
+            _ => Err(JsonError::new(
+                JsonErrorKind::UnexpectedToken {
+                    got: format!("{}", token.node),
+                    expected: "value",
+                },
+                token.span,
+            )),
+        }
+    }
+
+    /// Parse the next JSON value into a [`SpannedJsonValue`], the same way
+    /// [`Self::deserialize_json_value`] does but recording the [`Span`] of
+    /// each array element and object member as it's parsed.
+    fn deserialize_spanned_json_value(&mut self) -> Result<SpannedJsonValue<'input>> {
+        let token = self.next()?;
+        match token.node {
+            Token::Null => Ok(SpannedJsonValue::Null),
+            Token::True => Ok(SpannedJsonValue::Bool(true)),
+            Token::False => Ok(SpannedJsonValue::Bool(false)),
+            Token::String(s) => Ok(SpannedJsonValue::String(s)),
+            Token::F64(n) => Ok(SpannedJsonValue::F64(n)),
+            Token::I64(n) => Ok(SpannedJsonValue::I64(n)),
+            Token::U64(n) => Ok(SpannedJsonValue::U64(n)),
+            Token::I128(n) => Ok(SpannedJsonValue::I128(n)),
+            Token::U128(n) => Ok(SpannedJsonValue::U128(n)),
+            Token::LBracket => {
+                let mut items = Vec::new();
+                if matches!(self.peek()?.node, Token::RBracket) {
+                    self.next()?;
+                    return Ok(SpannedJsonValue::Array(items));
+                }
+                loop {
+                    let item_start = self.peek()?.span.start;
+                    let node = self.deserialize_spanned_json_value()?;
+                    let item_end = self.peek()?.span.start;
+                    items.push(Spanned {
+                        node,
+                        span: Span::new(item_start, item_end - item_start),
+                    });
+                    let next = self.next()?;
+                    match next.node {
+                        Token::Comma => continue,
+                        Token::RBracket => break,
+                        _ => {
+                            return Err(JsonError::new(
+                                JsonErrorKind::UnexpectedToken {
+                                    got: format!("{}", next.node),
+                                    expected: "',' or ']'",
+                                },
+                                next.span,
+                            ));
+                        }
+                    }
+                }
+                Ok(SpannedJsonValue::Array(items))
+            }
+            Token::LBrace => {
+                let mut members = Vec::new();
+                if matches!(self.peek()?.node, Token::RBrace) {
+                    self.next()?;
+                    return Ok(SpannedJsonValue::Object(members));
+                }
+                loop {
+                    let key_token = self.next()?;
+                    let key = match key_token.node {
+                        Token::String(s) => s,
+                        _ => {
+                            return Err(JsonError::new(
+                                JsonErrorKind::UnexpectedToken {
+                                    got: format!("{}", key_token.node),
+                                    expected: "string key",
+                                },
+                                key_token.span,
+                            ));
+                        }
+                    };
+                    let colon = self.next()?;
+                    if !matches!(colon.node, Token::Colon) {
+                        return Err(JsonError::new(
+                            JsonErrorKind::UnexpectedToken {
+                                got: format!("{}", colon.node),
+                                expected: "':'",
+                            },
+                            colon.span,
+                        ));
+                    }
+                    let value_start = self.peek()?.span.start;
+                    let node = self.deserialize_spanned_json_value()?;
+                    let value_end = self.peek()?.span.start;
+                    members.push((
+                        key,
+                        Spanned {
+                            node,
+                            span: Span::new(value_start, value_end - value_start),
+                        },
+                    ));
+                    let next = self.next()?;
+                    match next.node {
+                        Token::Comma => continue,
+                        Token::RBrace => break,
+                        _ => {
+                            return Err(JsonError::new(
+                                JsonErrorKind::UnexpectedToken {
+                                    got: format!("{}", next.node),
+                                    expected: "',' or '}'",
+                                },
+                                next.span,
+                            ));
+                        }
+                    }
+                }
+                Ok(SpannedJsonValue::Object(members))
+            }
+            _ => Err(JsonError::new(
+                JsonErrorKind::UnexpectedToken {
+                    got: format!("{}", token.node),
+                    expected: "value",
+                },
+                token.span,
+            )),
+        }
+    }
+
+    /// Deserialize a scalar value.
+    fn deserialize_scalar(&mut self, wip: &mut Partial<'input>) -> Result<()> {
+        let expected_type = wip.shape().type_identifier;
+        let token = self.next_expecting(expected_type)?;
+        log::trace!("deserialize_scalar: token={:?}", token.node);
+
+        match token.node {
+            Token::String(s) => {
+                // Try parse_from_str first if the type supports it (e.g., chrono types)
+                if wip.shape().vtable.parse.is_some() {
                     wip.parse_from_str(&s)?;
                 } else if wip.shape().type_identifier == "Cow" {
                     // Zero-copy Cow<str>: preserve borrowed/owned status
@@ -704,7 +1946,15 @@ impl<'input> JsonDeserializer<'input> {
     }
 
     /// Set a string value, handling &str, Cow<str>, and String appropriately.
-    fn set_string_value(&mut self, wip: &mut Partial<'input>, s: Cow<'input, str>) -> Result<()> {
+    ///
+    /// `Cow<'input, [T]>` fields need no equivalent special-casing here:
+    /// unlike a JSON string (one token, so borrowing straight from `input`
+    /// is possible when it has no escapes), a JSON array is parsed element
+    /// by element, so its members are never one contiguous span of `input`
+    /// to borrow from - `Cow<[T]>` goes through the same `Def::List`-driven
+    /// `deserialize_list` as `Vec<T>`/`Box<[T]>`/`Arc<[T]>` and always ends
+    /// up `Cow::Owned`, which is the only sound outcome for a general `T`.
+    pub(crate) fn set_string_value(&mut self, wip: &mut Partial<'input>, s: Cow<'input, str>) -> Result<()> {
         let shape = wip.shape();
 
         // Check if target is &str (shared reference to str)
@@ -739,8 +1989,108 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
+    /// Set a map key from its (always string) JSON representation, parsing
+    /// into the map's declared key type. JSON object keys are strings even
+    /// when the Rust `Map<K, V>` has a non-string `K` (e.g. `HashMap<u32, T>`),
+    /// so integer/float/bool/char/enum key types reuse the same scalar
+    /// parsing used for values; string-like keys keep the existing fast path.
+    /// This is the deserialize-side counterpart of `serialize_map_key`, which
+    /// stringifies the same non-string key types on the way out, so
+    /// `HashMap<u8, V>`/`HashMap<i32, V>` round-trip end-to-end.
+    fn set_map_key(&mut self, wip: &mut Partial<'input>, key: Cow<'input, str>, key_span: Span) -> Result<()> {
+        let shape = wip.shape();
+        match &shape.ty {
+            Type::Primitive(PrimitiveType::Numeric(NumericType::Integer { signed: true })) => {
+                let n: i64 = key.parse().map_err(|_| {
+                    JsonError::new(
+                        JsonErrorKind::InvalidValue {
+                            message: format!("`{key}` is not a valid integer map key"),
+                        },
+                        key_span,
+                    )
+                })?;
+                self.set_number_i64(wip, n, key_span)?;
+            }
+            Type::Primitive(PrimitiveType::Numeric(NumericType::Integer { signed: false })) => {
+                let n: u64 = key.parse().map_err(|_| {
+                    JsonError::new(
+                        JsonErrorKind::InvalidValue {
+                            message: format!("`{key}` is not a valid unsigned integer map key"),
+                        },
+                        key_span,
+                    )
+                })?;
+                self.set_number_u64(wip, n, key_span)?;
+            }
+            Type::Primitive(PrimitiveType::Numeric(NumericType::Float)) => {
+                let n: f64 = key.parse().map_err(|_| {
+                    JsonError::new(
+                        JsonErrorKind::InvalidValue {
+                            message: format!("`{key}` is not a valid floating-point map key"),
+                        },
+                        key_span,
+                    )
+                })?;
+                // `key_span` covers the JSON string token verbatim, quotes
+                // included, but `set_number_f64`'s f32 path re-slices the
+                // span straight out of `self.input` expecting a bare numeric
+                // literal like `3.14` - fed the quoted `"3.14"` it fails to
+                // parse and silently falls back to the lossier `n as f32`
+                // cast. Strip the surrounding quote bytes so it re-parses the
+                // same text `key` was built from.
+                let unquoted_span = Span::new(key_span.start() + 1, key_span.len().saturating_sub(2));
+                self.set_number_f64(wip, n, unquoted_span)?;
+            }
+            Type::Primitive(_) if shape.type_identifier == "bool" => match key.as_ref() {
+                "true" => {
+                    wip.set(true)?;
+                }
+                "false" => {
+                    wip.set(false)?;
+                }
+                _ => {
+                    return Err(JsonError::new(
+                        JsonErrorKind::InvalidValue {
+                            message: format!("`{key}` is not a valid boolean map key"),
+                        },
+                        key_span,
+                    ));
+                }
+            },
+            Type::Primitive(_) if shape.type_identifier == "char" => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => {
+                        wip.set(c)?;
+                    }
+                    _ => {
+                        return Err(JsonError::new(
+                            JsonErrorKind::InvalidValue {
+                                message: format!("`{key}` is not a single-character map key"),
+                            },
+                            key_span,
+                        ));
+                    }
+                }
+            }
+            Type::User(UserType::Enum(_)) => {
+                wip.select_variant_named(&key)?;
+            }
+            _ => {
+                if shape.inner.is_some() {
+                    wip.begin_inner()?;
+                    self.set_string_value(wip, key)?;
+                    wip.end()?;
+                } else {
+                    self.set_string_value(wip, key)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Set a numeric value, handling type conversions.
-    fn set_number_f64(&mut self, wip: &mut Partial<'input>, n: f64, span: Span) -> Result<()> {
+    pub(crate) fn set_number_f64(&mut self, wip: &mut Partial<'input>, n: f64, span: Span) -> Result<()> {
         let shape = wip.shape();
         let ty = match &shape.ty {
             Type::Primitive(PrimitiveType::Numeric(ty)) => ty,
@@ -770,7 +2120,20 @@ impl<'input> JsonDeserializer<'input> {
                 };
                 match size {
                     4 => {
-                        wip.set(n as f32)?;
+                        // Parsing `n as f32` double-rounds: the decimal text is first
+                        // rounded to the nearest `f64`, then that `f64` is rounded again
+                        // to the nearest `f32`, which can land one ULP away from the
+                        // correctly-rounded `f32` for the original decimal. Re-parse the
+                        // original source slice directly into `f32` instead, so there's
+                        // only a single rounding step.
+                        let text = core::str::from_utf8(
+                            &self.input[span.start()..span.start() + span.len()],
+                        )
+                        .ok();
+                        let v = text
+                            .and_then(|s| s.parse::<f32>().ok())
+                            .unwrap_or(n as f32);
+                        wip.set(v)?;
                     }
                     8 => {
                         wip.set(n)?;
@@ -806,7 +2169,7 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
-    fn set_number_i64(&mut self, wip: &mut Partial<'input>, n: i64, span: Span) -> Result<()> {
+    pub(crate) fn set_number_i64(&mut self, wip: &mut Partial<'input>, n: i64, span: Span) -> Result<()> {
         let shape = wip.shape();
         let size = match shape.layout {
             ShapeLayout::Sized(layout) => layout.size(),
@@ -917,7 +2280,7 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
-    fn set_number_u64(&mut self, wip: &mut Partial<'input>, n: u64, span: Span) -> Result<()> {
+    pub(crate) fn set_number_u64(&mut self, wip: &mut Partial<'input>, n: u64, span: Span) -> Result<()> {
         let shape = wip.shape();
         let size = match shape.layout {
             ShapeLayout::Sized(layout) => layout.size(),
@@ -1019,7 +2382,7 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
-    fn set_number_i128(&mut self, wip: &mut Partial<'input>, n: i128, span: Span) -> Result<()> {
+    pub(crate) fn set_number_i128(&mut self, wip: &mut Partial<'input>, n: i128, span: Span) -> Result<()> {
         let shape = wip.shape();
         let size = match shape.layout {
             ShapeLayout::Sized(layout) => layout.size(),
@@ -1052,7 +2415,7 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
-    fn set_number_u128(&mut self, wip: &mut Partial<'input>, n: u128, span: Span) -> Result<()> {
+    pub(crate) fn set_number_u128(&mut self, wip: &mut Partial<'input>, n: u128, span: Span) -> Result<()> {
         let shape = wip.shape();
         let size = match shape.layout {
             ShapeLayout::Sized(layout) => layout.size(),
@@ -1144,8 +2507,12 @@ impl<'input> JsonDeserializer<'input> {
 
         // Check if the struct has a default attribute (all missing fields use defaults)
         let struct_has_default = wip.shape().has_default_attr();
-        // Check if the struct denies unknown fields
-        let deny_unknown_fields = wip.shape().has_deny_unknown_fields_attr();
+        // Check if the struct denies unknown fields, either on the type
+        // itself or via the deserializer-wide `deny_unknown_fields` option
+        let deny_unknown_fields =
+            self.deny_unknown_fields || wip.shape().has_deny_unknown_fields_attr();
+        // Check for an explicit #[facet(rename_all = "...")] convention
+        let rename_all = wip.shape().get_rename_all_attr();
 
         // Parse fields until closing brace
         loop {
@@ -1182,34 +2549,97 @@ impl<'input> JsonDeserializer<'input> {
                         .fields
                         .iter()
                         .enumerate()
-                        .find(|(_, f)| f.name == key.as_ref());
+                        .find(|(_, f)| field_name_matches(f.name, &key, rename_all));
 
                     if let Some((idx, field)) = field_info {
-                        wip.begin_field(field.name)?;
-                        // Check if field has custom deserialization
-                        if field.vtable.deserialize_with.is_some() {
-                            wip.begin_custom_deserialization()?;
-                            self.deserialize_into(wip)?;
-                            wip.end()?; // Calls deserialize_with function
+                        if fields_set[idx] && self.duplicate_keys != DuplicateKeyPolicy::LastWins {
+                            // Repeat key under a policy other than the
+                            // (default) last-one-wins behavior: keep the
+                            // first occurrence's value and discard this one.
+                            if self.duplicate_keys == DuplicateKeyPolicy::Error {
+                                self.record_error(JsonError::new(
+                                    JsonErrorKind::DuplicateKey {
+                                        key: key.to_string(),
+                                    },
+                                    _key_span,
+                                ))?;
+                            }
+                            self.skip_value()?;
                         } else {
-                            self.deserialize_into(wip)?;
+                            wip.begin_field(field.name)?;
+                            self.path_stack.push(PathComponent::Field(field.name));
+                            // Check if field has custom deserialization. There's no
+                            // dedicated field-level wire-encoding attribute (e.g. a
+                            // per-field base64/hex override) - only the crate-wide
+                            // `DeserializerOptions::byte_encoding` - so a caller that
+                            // needs one field to use its own encoding reaches for a
+                            // manual `deserialize_with` function instead.
+                            if field.vtable.deserialize_with.is_some() {
+                                if let Err(e) = wip.begin_custom_deserialization() {
+                                    self.path_stack.pop();
+                                    return Err(e.into());
+                                }
+                                if let Err(e) = self.deserialize_into(wip) {
+                                    self.path_stack.pop();
+                                    return Err(e);
+                                }
+                                self.path_stack.pop();
+                                wip.end()?; // Calls deserialize_with function
+                            } else {
+                                let is_scalar_leaf = matches!(wip.shape().def, Def::Scalar);
+                                if let Err(e) = self.deserialize_into(wip) {
+                                    if self.collect_errors && is_scalar_leaf {
+                                        // A scalar leaf consumes exactly one
+                                        // token before it can fail, so the
+                                        // tokenizer is still in sync - record
+                                        // the diagnostic, fall back to the
+                                        // field's default, and keep parsing the
+                                        // rest of the object instead of
+                                        // aborting on the first bad leaf.
+                                        self.record_error(e)?;
+                                        wip.set_default()?;
+                                    } else if self.collect_errors {
+                                        // A nested list/map/struct field failed
+                                        // partway through its own value, so the
+                                        // tokenizer could be anywhere inside the
+                                        // malformed structure - resynchronize by
+                                        // skipping to the next sibling key before
+                                        // falling back to the field's default.
+                                        self.record_error(e)?;
+                                        wip.set_default()?;
+                                        self.path_stack.pop();
+                                        wip.end()?;
+                                        fields_set[idx] = true;
+                                        self.resync_after_error()?;
+                                        let next = self.peek()?;
+                                        if matches!(next.node, Token::Comma) {
+                                            self.next()?;
+                                        }
+                                        continue;
+                                    } else {
+                                        self.path_stack.pop();
+                                        return Err(e);
+                                    }
+                                }
+                                self.path_stack.pop();
+                            }
+                            wip.end()?;
+                            fields_set[idx] = true;
                         }
-                        wip.end()?;
-                        fields_set[idx] = true;
                     } else {
                         // Unknown field
                         if deny_unknown_fields {
                             let expected_fields: Vec<&'static str> =
                                 struct_def.fields.iter().map(|f| f.name).collect();
                             let suggestion = find_similar_field(&key, &expected_fields);
-                            return Err(JsonError::new(
+                            self.record_error(JsonError::new(
                                 JsonErrorKind::UnknownField {
                                     field: key.into_owned(),
                                     expected: expected_fields,
                                     suggestion,
                                 },
                                 _key_span,
-                            ));
+                            ))?;
                         }
                         log::trace!("skipping unknown field: {}", key);
                         self.skip_value()?;
@@ -1255,8 +2685,10 @@ impl<'input> JsonDeserializer<'input> {
                 // Struct-level #[facet(default)] - use the field type's Default
                 wip.set_nth_field_to_default(idx)?;
             } else {
-                // Required field is missing - raise our own error with spans
-                return Err(JsonError {
+                // Required field is missing - raise our own error with spans.
+                // In error-accumulation mode this is recorded at the object's
+                // closing brace and the remaining fields are still checked.
+                self.record_error(JsonError {
                     kind: JsonErrorKind::MissingField {
                         field: field.name,
                         object_start: Some(object_start_span),
@@ -1264,7 +2696,8 @@ impl<'input> JsonDeserializer<'input> {
                     },
                     span: None, // We use custom labels instead
                     source_code: None,
-                });
+                    path: self.path_stack.clone(),
+                })?;
             }
         }
 
@@ -1276,12 +2709,34 @@ impl<'input> JsonDeserializer<'input> {
     /// This uses a two-pass approach:
     /// 1. Peek mode: Scan all keys, feed to solver, record value positions
     /// 2. Deserialize: Use the resolved Configuration to deserialize with proper path handling
+    ///
+    /// If the struct has a catch-all flatten map (a flattened field whose
+    /// type is a map, e.g. `#[facet(flatten)] extra: HashMap<String,
+    /// JsonValue>`), every key the solver didn't resolve to a named or
+    /// flattened field is inserted into it after pass 2, preserving its
+    /// original JSON shape.
+    ///
+    /// Known limitation: keys are fed to `solver.see_key` verbatim and
+    /// resolved against the schema's field names directly, so a
+    /// `#[facet(rename_all = "...")]` on a type reached only through
+    /// flattening isn't applied here the way [`field_name_matches`] applies
+    /// it for a directly-nested struct - doing so would require teaching
+    /// `facet_solver::Schema` about the convention, not just this file.
     fn deserialize_struct_with_flatten(&mut self, wip: &mut Partial<'input>) -> Result<()> {
         log::trace!(
             "deserialize_struct_with_flatten: {}",
             wip.shape().type_identifier
         );
 
+        // A flattened map field (e.g. `#[facet(flatten)] extra:
+        // HashMap<String, JsonValue>`) isn't modeled by `facet_solver::Schema`
+        // as a set of named fields, so the solver never claims keys for it -
+        // any key it leaves unclaimed is exactly the catch-all's payload.
+        let catch_all_field = match &wip.shape().ty {
+            Type::User(UserType::Struct(s)) => Self::find_flatten_map_field(s),
+            _ => None,
+        };
+
         // Build the schema for this type with auto-detection of enum representations
         // This respects #[facet(tag = "...", content = "...")] and #[facet(untagged)] attributes
         let schema = Schema::build_auto(wip.shape()).map_err(|e| {
@@ -1293,11 +2748,15 @@ impl<'input> JsonDeserializer<'input> {
         // Create the solver
         let mut solver = Solver::new(&schema);
 
-        // Track where values start so we can re-read them in pass 2
-        let mut field_positions: Vec<(&'static str, usize)> = Vec::new();
+        // A tape of (key, key span, value span) triples recorded during pass 1,
+        // so pass 2 can seek directly to each value's exact byte range instead
+        // of re-lexing from an unbounded offset into the rest of the document,
+        // and so an unclaimed key can still be reported at its own position.
+        let mut tape: Vec<(&'static str, Span, Span)> = Vec::new();
 
         // Expect opening brace
         let token = self.next()?;
+        let object_span = token.span;
         match token.node {
             Token::LBrace => {}
             _ => {
@@ -1348,10 +2807,16 @@ impl<'input> JsonDeserializer<'input> {
                     // Feed key to solver (decision not used in peek mode)
                     let _decision = solver.see_key(key_static);
 
-                    field_positions.push((key_static, value_start));
-
-                    // Skip the value
+                    // Skip the value, then record its exact tape span - the
+                    // next token (the following comma or the closing brace)
+                    // starts right where this value's bytes end.
                     self.skip_value()?;
+                    let value_end = self.peek()?.span.start;
+                    tape.push((
+                        key_static,
+                        key_token.span,
+                        Span::new(value_start, value_end - value_start),
+                    ));
 
                     // Check for comma
                     let next = self.peek()?;
@@ -1375,18 +2840,46 @@ impl<'input> JsonDeserializer<'input> {
         // ========== Get the resolved Configuration ==========
         // Get seen keys before finish() consumes the solver
         let seen_keys = solver.seen_keys().clone();
-        let config = solver
-            .finish()
-            .map_err(|e| JsonError::without_span(JsonErrorKind::Solver(format!("{e}"))))?;
+        // `solver.finish()` is also where ambiguous or unsatisfiable
+        // content-driven (untagged) flatten-enum resolution surfaces - e.g.
+        // two candidate variants equally matching the keys seen so far, or
+        // none of a variant's required fields being covered - so point the
+        // error at the object itself rather than leaving it unspanned.
+        let config = solver.finish().map_err(|e| {
+            JsonError::new(JsonErrorKind::Solver(format!("{e}")), object_span)
+        })?;
 
         // ========== PASS 2: Deserialize with proper path handling ==========
         // Sort fields by path depth (deepest first within each prefix group)
         // This ensures we set all fields at a given nesting level before closing it
-        let mut fields_to_process: Vec<_> = field_positions
+        let mut fields_to_process: Vec<_> = tape
             .iter()
-            .filter_map(|(key, offset)| config.field(key).map(|info| (info, *offset)))
+            .filter_map(|(key, _key_span, span)| config.field(key).map(|info| (info, *span)))
             .collect();
 
+        // A strict type rejects keys that no field - flattened or direct -
+        // claimed, mirroring `deserialize_struct_simple`'s deny_unknown_fields
+        // handling. This must run after the solver has resolved every field's
+        // path, so that keys legitimately consumed by a flattened child are
+        // never mistaken for unknown. A catch-all flatten map claims whatever
+        // is left over, so it also keeps this check quiet.
+        let deny_unknown_fields =
+            self.deny_unknown_fields || wip.shape().has_deny_unknown_fields_attr();
+        if deny_unknown_fields && catch_all_field.is_none() {
+            for (key, key_span, _) in &tape {
+                if config.field(key).is_none() {
+                    self.record_error(JsonError::new(
+                        JsonErrorKind::UnknownField {
+                            field: key.to_string(),
+                            expected: Vec::new(),
+                            suggestion: None,
+                        },
+                        *key_span,
+                    ))?;
+                }
+            }
+        }
+
         // Sort by path to group nested fields together
         // We want to process in an order that allows proper begin/end management
         fields_to_process.sort_by(|(a, _), (b, _)| a.path.segments().cmp(b.path.segments()));
@@ -1394,9 +2887,9 @@ impl<'input> JsonDeserializer<'input> {
         // Track currently open path segments: (field_name, is_option)
         let mut open_segments: Vec<(&str, bool)> = Vec::new();
 
-        for (field_info, offset) in &fields_to_process {
+        for (field_info, span) in &fields_to_process {
             let segments = field_info.path.segments();
-            let offset = *offset;
+            let span = *span;
 
             // Extract just the field names from the path (ignoring Variant segments for now)
             let field_segments: Vec<&str> = segments
@@ -1445,8 +2938,10 @@ impl<'input> JsonDeserializer<'input> {
                 }
             }
 
-            // Create sub-deserializer and deserialize the value
-            let mut sub = Self::from_offset(self.input, offset);
+            // Create a sub-deserializer bounded to exactly this value's tape
+            // span, rather than an unbounded suffix of the document - the
+            // tokenizer can't run past the value's recorded end.
+            let mut sub = Self::from_offset(&self.input[..span.end()], span.start());
 
             if ends_with_variant {
                 sub.deserialize_variant_struct_content(wip)?;
@@ -1496,39 +2991,440 @@ impl<'input> JsonDeserializer<'input> {
                 if let Some(PathSegment::Field(name)) = info.path.segments().first() {
                     Some(*name)
                 } else {
-                    None
+                    None
+                }
+            })
+            .collect();
+
+        // For each missing first segment that we didn't process, check if it's Option
+        for first_field in missing_first_segments {
+            if processed_first_segments.contains(first_field) {
+                // We processed some fields under this, so the field was already handled
+                continue;
+            }
+
+            log::trace!(
+                "setting default for flattened Option field: {}",
+                first_field
+            );
+
+            wip.begin_field(first_field)?;
+            if matches!(wip.shape().def, Def::Option(_)) {
+                // This is a flattened Option field with ALL inner fields missing, set to None
+                wip.set_default()?;
+            }
+            wip.end()?;
+        }
+
+        // Route every key the solver didn't claim for a named or flattened
+        // field into the catch-all map, preserving each value's own JSON
+        // shape (it's typically a `JsonValue`, but anything the map's value
+        // type can parse works).
+        if let Some(map_field) = catch_all_field {
+            wip.begin_field(map_field.name)?;
+            wip.begin_map()?;
+            for (key, _key_span, span) in &tape {
+                if config.field(key).is_some() {
+                    continue;
+                }
+                wip.begin_key()?;
+                self.set_string_value(wip, Cow::Borrowed(*key))?;
+                wip.end()?;
+                wip.begin_value()?;
+                let mut sub = Self::from_offset(&self.input[..span.end()], span.start());
+                sub.deserialize_into(wip)?;
+                wip.end()?;
+            }
+            // begin_map() does not push a frame, so there's no map-level end()
+            wip.end()?; // end the catch-all field itself
+        }
+
+        Ok(())
+    }
+
+    /// Deserialize an enum.
+    ///
+    /// Dispatches on the shape's tagging attributes (mirroring the serializer's
+    /// logic in `serialize_value`): `#[facet(tag = "...", content = "...")]` for
+    /// adjacent tagging, `#[facet(tag = "...")]` alone for internal tagging, and
+    /// falls back to the default externally tagged representation otherwise.
+    fn deserialize_enum(&mut self, wip: &mut Partial<'input>) -> Result<()> {
+        let shape = wip.shape();
+        if shape.is_untagged() {
+            return self.deserialize_enum_untagged(wip);
+        }
+        if let Some(tag) = shape.get_tag_attr() {
+            if let Some(content) = shape.get_content_attr() {
+                return self.deserialize_enum_adjacently_tagged(wip, tag, content);
+            }
+            return self.deserialize_enum_internally_tagged(wip, tag);
+        }
+        self.deserialize_enum_externally_tagged(wip)
+    }
+
+    /// Deserialize an untagged enum: no discriminant is present, so each
+    /// variant is tried in declaration order against the same input,
+    /// accepting the first one that parses successfully.
+    ///
+    /// Each attempt reads from its own sub-deserializer created at the
+    /// value's start offset, so a failed attempt never advances this
+    /// deserializer's own token stream. A failed attempt's field writes are
+    /// superseded by the next variant's `select_variant_named` call rather
+    /// than explicitly unwound - this crate doesn't expose a way to
+    /// snapshot/restore a `Partial` outside of reselecting the active
+    /// variant. If every variant fails, the errors are aggregated into one
+    /// `InvalidValue`.
+    fn deserialize_enum_untagged(&mut self, wip: &mut Partial<'input>) -> Result<()> {
+        let shape = wip.shape();
+        let enum_def = match &shape.ty {
+            Type::User(UserType::Enum(e)) => e,
+            _ => {
+                return Err(JsonError::without_span(JsonErrorKind::InvalidValue {
+                    message: "expected enum type".into(),
+                }));
+            }
+        };
+
+        let start = self.peek()?.span.start;
+        let mut failures: Vec<String> = Vec::new();
+
+        for variant in enum_def.variants {
+            let mut attempt = Self::from_offset(self.input, start);
+            let outcome = wip
+                .select_variant_named(variant.name)
+                .map_err(JsonError::from)
+                .and_then(|()| attempt.deserialize_variant_struct_content(wip));
+
+            match outcome {
+                Ok(()) => {
+                    // Advance this deserializer's own cursor past the value
+                    // we just replayed into `wip`.
+                    self.skip_value()?;
+                    return Ok(());
+                }
+                Err(e) => failures.push(format!("`{}`: {e}", variant.name)),
+            }
+        }
+
+        Err(JsonError::new(
+            JsonErrorKind::InvalidValue {
+                message: format!(
+                    "no variant of `{}` matched the input ({})",
+                    shape.type_identifier,
+                    failures.join("; ")
+                ),
+            },
+            Span::new(start, 0),
+        ))
+    }
+
+    /// Deserialize an internally tagged enum: `{"type": "Variant", ...fields}`.
+    ///
+    /// The discriminant may appear anywhere among the object's members, so this
+    /// buffers the byte offset of every non-tag member during a first pass, then
+    /// selects the variant and replays each member via `from_offset` once it's
+    /// known which struct fields they belong to.
+    fn deserialize_enum_internally_tagged(
+        &mut self,
+        wip: &mut Partial<'input>,
+        tag: &str,
+    ) -> Result<()> {
+        log::trace!("deserialize_enum_internally_tagged: tag={tag}");
+
+        let open = self.next()?;
+        let object_span = open.span;
+        if !matches!(open.node, Token::LBrace) {
+            return Err(JsonError::new(
+                JsonErrorKind::UnexpectedToken {
+                    got: format!("{}", open.node),
+                    expected: "'{' for internally tagged enum",
+                },
+                object_span,
+            ));
+        }
+
+        let mut variant_name: Option<String> = None;
+        let mut variant_name_span: Option<Span> = None;
+        let mut member_positions: Vec<(String, usize)> = Vec::new();
+
+        loop {
+            let token = self.peek()?;
+            match &token.node {
+                Token::RBrace => {
+                    self.next()?;
+                    break;
+                }
+                Token::String(_) => {
+                    let key_token = self.next()?;
+                    let key = match key_token.node {
+                        Token::String(s) => s,
+                        _ => unreachable!(),
+                    };
+                    let colon = self.next()?;
+                    if !matches!(colon.node, Token::Colon) {
+                        return Err(JsonError::new(
+                            JsonErrorKind::UnexpectedToken {
+                                got: format!("{}", colon.node),
+                                expected: "':'",
+                            },
+                            colon.span,
+                        ));
+                    }
+
+                    if key.as_ref() == tag {
+                        let value_token = self.next_expecting("tag discriminant")?;
+                        variant_name_span = Some(value_token.span);
+                        match value_token.node {
+                            Token::String(s) => variant_name = Some(s.into_owned()),
+                            _ => {
+                                return Err(JsonError::new(
+                                    JsonErrorKind::InvalidValue {
+                                        message: format!(
+                                            "tag field `{tag}` must be a string, got {}",
+                                            value_token.node
+                                        ),
+                                    },
+                                    object_span,
+                                ));
+                            }
+                        }
+                    } else {
+                        let value_start = self.peek()?.span.start;
+                        self.skip_value()?;
+                        member_positions.push((key.into_owned(), value_start));
+                    }
+
+                    let next = self.peek()?;
+                    if matches!(next.node, Token::Comma) {
+                        self.next()?;
+                    }
+                }
+                _ => {
+                    let span = token.span;
+                    return Err(JsonError::new(
+                        JsonErrorKind::UnexpectedToken {
+                            got: format!("{}", token.node),
+                            expected: "field name or '}'",
+                        },
+                        span,
+                    ));
+                }
+            }
+        }
+
+        let variant_name = variant_name.ok_or_else(|| {
+            JsonError::new(
+                JsonErrorKind::InvalidValue {
+                    message: format!("missing tag field `{tag}`"),
+                },
+                object_span,
+            )
+        })?;
+        select_variant_with_suggestion(wip, &variant_name, variant_name_span.unwrap_or(object_span))?;
+
+        self.deserialize_buffered_variant_fields(wip, &member_positions)
+    }
+
+    /// Deserialize an adjacently tagged enum: `{"t": "Variant", "c": {...}}`.
+    ///
+    /// Like the internal-tagging case, the tag and content keys may appear in
+    /// either order, so the content's byte offset is buffered and replayed via
+    /// `from_offset` once the variant has been selected.
+    fn deserialize_enum_adjacently_tagged(
+        &mut self,
+        wip: &mut Partial<'input>,
+        tag: &str,
+        content: &str,
+    ) -> Result<()> {
+        log::trace!("deserialize_enum_adjacently_tagged: tag={tag}, content={content}");
+
+        let open = self.next()?;
+        if !matches!(open.node, Token::LBrace) {
+            return Err(JsonError::new(
+                JsonErrorKind::UnexpectedToken {
+                    got: format!("{}", open.node),
+                    expected: "'{' for adjacently tagged enum",
+                },
+                open.span,
+            ));
+        }
+
+        let mut variant_name: Option<String> = None;
+        let mut variant_name_span: Option<Span> = None;
+        let mut content_offset: Option<usize> = None;
+
+        loop {
+            let token = self.peek()?;
+            match &token.node {
+                Token::RBrace => {
+                    self.next()?;
+                    break;
+                }
+                Token::String(_) => {
+                    let key_token = self.next()?;
+                    let key = match key_token.node {
+                        Token::String(s) => s,
+                        _ => unreachable!(),
+                    };
+                    let colon = self.next()?;
+                    if !matches!(colon.node, Token::Colon) {
+                        return Err(JsonError::new(
+                            JsonErrorKind::UnexpectedToken {
+                                got: format!("{}", colon.node),
+                                expected: "':'",
+                            },
+                            colon.span,
+                        ));
+                    }
+
+                    if key.as_ref() == tag {
+                        let value_token = self.next_expecting("tag discriminant")?;
+                        variant_name_span = Some(value_token.span);
+                        match value_token.node {
+                            Token::String(s) => variant_name = Some(s.into_owned()),
+                            _ => {
+                                return Err(JsonError::new(
+                                    JsonErrorKind::UnexpectedToken {
+                                        got: format!("{}", value_token.node),
+                                        expected: "string tag value",
+                                    },
+                                    value_token.span,
+                                ));
+                            }
+                        }
+                    } else if key.as_ref() == content {
+                        content_offset = Some(self.peek()?.span.start);
+                        self.skip_value()?;
+                    } else {
+                        return Err(JsonError::new(
+                            JsonErrorKind::UnexpectedToken {
+                                got: format!("key `{key}`"),
+                                expected: "only the tag and content keys",
+                            },
+                            key_token.span,
+                        ));
+                    }
+
+                    let next = self.peek()?;
+                    if matches!(next.node, Token::Comma) {
+                        self.next()?;
+                    }
+                }
+                _ => {
+                    let span = token.span;
+                    return Err(JsonError::new(
+                        JsonErrorKind::UnexpectedToken {
+                            got: format!("{}", token.node),
+                            expected: "field name or '}'",
+                        },
+                        span,
+                    ));
+                }
+            }
+        }
+
+        let variant_name = variant_name.ok_or_else(|| {
+            JsonError::without_span(JsonErrorKind::InvalidValue {
+                message: format!("missing tag field `{tag}`"),
+            })
+        })?;
+        select_variant_with_suggestion(
+            wip,
+            &variant_name,
+            variant_name_span.unwrap_or(open.span),
+        )?;
+
+        match content_offset {
+            Some(offset) => {
+                let mut sub = Self::from_offset(self.input, offset);
+                sub.deserialize_variant_struct_content(wip)
+            }
+            None => {
+                let variant = wip.selected_variant().ok_or_else(|| {
+                    JsonError::without_span(JsonErrorKind::InvalidValue {
+                        message: "failed to get selected variant".into(),
+                    })
+                })?;
+                if variant.data.fields.is_empty() {
+                    Ok(())
+                } else {
+                    Err(JsonError::without_span(JsonErrorKind::MissingField {
+                        field: content,
+                        object_start: None,
+                        object_end: None,
+                    }))
+                }
+            }
+        }
+    }
+
+    /// Deserialize a selected variant's struct fields from buffered
+    /// `(field name, byte offset)` positions, replaying each one via
+    /// `from_offset`. Used by the internally tagged path where the
+    /// discriminant may not be the first object member.
+    fn deserialize_buffered_variant_fields(
+        &mut self,
+        wip: &mut Partial<'input>,
+        positions: &[(String, usize)],
+    ) -> Result<()> {
+        let variant = wip.selected_variant().ok_or_else(|| {
+            JsonError::without_span(JsonErrorKind::InvalidValue {
+                message: "failed to get selected variant".into(),
+            })
+        })?;
+
+        if variant.data.kind == StructKind::Unit {
+            // An internally tagged unit variant carries no payload, so any
+            // sibling keys in the object besides the tag are simply ignored.
+            return Ok(());
+        }
+
+        let fields = variant.data.fields;
+        let mut fields_set = alloc::vec![false; fields.len()];
+
+        for (key, offset) in positions {
+            if let Some((idx, field)) = fields
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.name == key.as_str())
+            {
+                wip.begin_field(field.name)?;
+                let mut sub = Self::from_offset(self.input, *offset);
+                if field.vtable.deserialize_with.is_some() {
+                    wip.begin_custom_deserialization()?;
+                    sub.deserialize_into(wip)?;
+                    wip.end()?;
+                } else {
+                    sub.deserialize_into(wip)?;
                 }
-            })
-            .collect();
+                wip.end()?;
+                fields_set[idx] = true;
+            }
+        }
 
-        // For each missing first segment that we didn't process, check if it's Option
-        for first_field in missing_first_segments {
-            if processed_first_segments.contains(first_field) {
-                // We processed some fields under this, so the field was already handled
+        for (idx, field) in fields.iter().enumerate() {
+            if fields_set[idx] {
                 continue;
             }
-
-            log::trace!(
-                "setting default for flattened Option field: {}",
-                first_field
-            );
-
-            wip.begin_field(first_field)?;
-            if matches!(wip.shape().def, Def::Option(_)) {
-                // This is a flattened Option field with ALL inner fields missing, set to None
-                wip.set_default()?;
+            if field.flags.contains(FieldFlags::DEFAULT) || field.vtable.default_fn.is_some() {
+                wip.set_nth_field_to_default(idx)?;
+            } else {
+                return Err(JsonError::without_span(JsonErrorKind::MissingField {
+                    field: field.name,
+                    object_start: None,
+                    object_end: None,
+                }));
             }
-            wip.end()?;
         }
 
         Ok(())
     }
 
-    /// Deserialize an enum.
+    /// Deserialize an externally tagged enum.
     ///
     /// Supports externally tagged representation: `{"VariantName": data}` or `"UnitVariant"`
-    fn deserialize_enum(&mut self, wip: &mut Partial<'input>) -> Result<()> {
-        log::trace!("deserialize_enum: {}", wip.shape().type_identifier);
+    fn deserialize_enum_externally_tagged(&mut self, wip: &mut Partial<'input>) -> Result<()> {
+        log::trace!("deserialize_enum_externally_tagged: {}", wip.shape().type_identifier);
 
         let token = self.peek()?;
 
@@ -1536,9 +3432,16 @@ impl<'input> JsonDeserializer<'input> {
             // String = unit variant (externally tagged unit)
             Token::String(s) => {
                 let variant_name = s.clone();
+                let variant_span = token.span;
                 self.next()?; // consume
 
-                wip.select_variant_named(&variant_name)?;
+                let rename_all = wip.shape().get_rename_all_attr();
+                let resolved = resolve_variant_name(wip.shape(), &variant_name, rename_all);
+                select_variant_with_suggestion(
+                    wip,
+                    resolved.unwrap_or(&variant_name),
+                    variant_span,
+                )?;
                 // Unit variants don't need further deserialization
                 Ok(())
             }
@@ -1583,7 +3486,9 @@ impl<'input> JsonDeserializer<'input> {
                 }
 
                 // Select the variant
-                wip.select_variant_named(&key)?;
+                let rename_all = wip.shape().get_rename_all_attr();
+                let resolved = resolve_variant_name(wip.shape(), &key, rename_all);
+                select_variant_with_suggestion(wip, resolved.unwrap_or(&key), key_token.span)?;
 
                 // Get the selected variant info to determine how to deserialize
                 let variant = wip.selected_variant().ok_or_else(|| {
@@ -1717,6 +3622,22 @@ impl<'input> JsonDeserializer<'input> {
             })
         })?;
 
+        if variant.data.kind == StructKind::Unit {
+            // Unit variant - the untagged serializer writes this as `null`.
+            let token = self.next()?;
+            return if matches!(token.node, Token::Null) {
+                Ok(())
+            } else {
+                Err(JsonError::new(
+                    JsonErrorKind::UnexpectedToken {
+                        got: format!("{}", token.node),
+                        expected: "'null' for unit variant",
+                    },
+                    token.span,
+                ))
+            };
+        }
+
         let is_struct_variant = variant
             .data
             .fields
@@ -1764,6 +3685,8 @@ impl<'input> JsonDeserializer<'input> {
             ));
         }
 
+        let mut fields_set = alloc::vec![false; fields.len()];
+
         loop {
             let token = self.peek()?;
             if matches!(token.node, Token::RBrace) {
@@ -1797,9 +3720,13 @@ impl<'input> JsonDeserializer<'input> {
             }
 
             // Find the field in the variant's fields to check for custom deserialization
-            let field_info = fields.iter().find(|f| f.name == field_name.as_ref());
+            let rename_all = wip.shape().get_rename_all_attr();
+            let field_info = fields
+                .iter()
+                .enumerate()
+                .find(|(_, f)| field_name_matches(f.name, &field_name, rename_all));
 
-            if let Some(field) = field_info {
+            if let Some((idx, field)) = field_info {
                 wip.begin_field(field.name)?;
                 // Check if field has custom deserialization
                 if field.vtable.deserialize_with.is_some() {
@@ -1810,6 +3737,19 @@ impl<'input> JsonDeserializer<'input> {
                     self.deserialize_into(wip)?;
                 }
                 wip.end()?;
+                fields_set[idx] = true;
+            } else if self.deny_unknown_fields || wip.shape().has_deny_unknown_fields_attr() {
+                let expected_fields: Vec<&'static str> = fields.iter().map(|f| f.name).collect();
+                let suggestion = find_similar_field(&field_name, &expected_fields);
+                self.record_error(JsonError::new(
+                    JsonErrorKind::UnknownField {
+                        field: field_name.into_owned(),
+                        expected: expected_fields,
+                        suggestion,
+                    },
+                    key_token.span,
+                ))?;
+                self.skip_value()?;
             } else {
                 // Unknown field, skip its value
                 self.skip_value()?;
@@ -1821,6 +3761,26 @@ impl<'input> JsonDeserializer<'input> {
             }
         }
 
+        // Same missing-field handling as
+        // `Self::deserialize_buffered_variant_fields` - apply a default
+        // where one's available, otherwise reject the variant outright
+        // instead of leaving the field uninitialized for `wip.build()` to
+        // trip over later.
+        for (idx, field) in fields.iter().enumerate() {
+            if fields_set[idx] {
+                continue;
+            }
+            if field.flags.contains(FieldFlags::DEFAULT) || field.vtable.default_fn.is_some() {
+                wip.set_nth_field_to_default(idx)?;
+            } else {
+                return Err(JsonError::without_span(JsonErrorKind::MissingField {
+                    field: field.name,
+                    object_start: None,
+                    object_end: None,
+                }));
+            }
+        }
+
         Ok(())
     }
 
@@ -1861,6 +3821,65 @@ impl<'input> JsonDeserializer<'input> {
         Ok(())
     }
 
+    /// Decode a base64/hex JSON string into a `Vec<u8>`/`[u8; N]` target, per
+    /// [`DeserializerOptions::byte_encoding`]. Only called once the caller
+    /// has already confirmed the next token is a string.
+    fn deserialize_byte_string(&mut self, wip: &mut Partial<'input>) -> Result<()> {
+        let token = self.next()?;
+        let s = match token.node {
+            Token::String(s) => s,
+            _ => unreachable!("caller already confirmed a string token"),
+        };
+
+        let decode = match self.byte_encoding {
+            ByteEncoding::Base64 => decode_base64,
+            ByteEncoding::Base64Url => decode_base64_url,
+            ByteEncoding::Hex => decode_hex,
+            ByteEncoding::Array => unreachable!("caller already checked byte_encoding != Array"),
+        };
+        let bytes = decode(&s).map_err(|(message, offset)| {
+            JsonError::new(
+                JsonErrorKind::InvalidValue {
+                    message: format!("{message} at offset {offset} in decoded string"),
+                },
+                token.span,
+            )
+        })?;
+
+        match &wip.shape().def {
+            Def::List(_) => {
+                wip.begin_list()?;
+                for byte in bytes {
+                    wip.begin_list_item()?;
+                    wip.set(byte)?;
+                    wip.end()?;
+                }
+            }
+            Def::Array(arr) => {
+                if bytes.len() != arr.n {
+                    return Err(JsonError::new(
+                        JsonErrorKind::InvalidValue {
+                            message: format!(
+                                "expected {} bytes, decoded string has {}",
+                                arr.n,
+                                bytes.len()
+                            ),
+                        },
+                        token.span,
+                    ));
+                }
+                for (i, byte) in bytes.into_iter().enumerate() {
+                    wip.begin_nth_field(i)?;
+                    wip.set(byte)?;
+                    wip.end()?;
+                }
+            }
+            _ => unreachable!("caller already checked is_u8_sequence_shape"),
+        }
+
+        Ok(())
+    }
+
     /// Deserialize a list/Vec.
     fn deserialize_list(&mut self, wip: &mut Partial<'input>) -> Result<()> {
         log::trace!("deserialize_list");
@@ -1878,6 +3897,7 @@ impl<'input> JsonDeserializer<'input> {
 
         wip.begin_list()?;
 
+        let mut index = 0;
         loop {
             let token = self.peek()?;
             if matches!(token.node, Token::RBracket) {
@@ -1886,8 +3906,26 @@ impl<'input> JsonDeserializer<'input> {
             }
 
             wip.begin_list_item()?;
-            self.deserialize_into(wip)?;
+            self.path_stack.push(PathComponent::Index(index));
+            let result = self.deserialize_into(wip);
+            self.path_stack.pop();
+            if let Err(e) = result {
+                if self.collect_errors {
+                    // The element failed partway through its own value, so
+                    // the tokenizer could be anywhere inside it -
+                    // resynchronize by skipping to the next element before
+                    // falling back to a default placeholder item.
+                    self.record_error(e)?;
+                    wip.set_default()?;
+                    wip.end()?;
+                    index += 1;
+                    self.resync_after_error()?;
+                    continue;
+                }
+                return Err(e);
+            }
             wip.end()?; // End the list item frame
+            index += 1;
 
             let next = self.peek()?;
             if matches!(next.node, Token::Comma) {
@@ -1950,22 +3988,28 @@ impl<'input> JsonDeserializer<'input> {
                 ));
             }
 
-            // Set key - begin_key pushes a frame for the key type
+            // Set key - begin_key pushes a frame for the key type. JSON object
+            // keys are always strings, but the map's `K` may be an integer,
+            // bool, char, or enum type, so parse the string into whatever
+            // `K` actually is.
             wip.begin_key()?;
-            // For transparent types (like UserId(String)), we need to use begin_inner
-            // to set the inner String value
-            if wip.shape().inner.is_some() {
-                wip.begin_inner()?;
-                self.set_string_value(wip, key)?;
-                wip.end()?;
-            } else {
-                self.set_string_value(wip, key)?;
-            }
+            self.set_map_key(wip, key, key_token.span)?;
             wip.end()?;
 
             // Value - begin_value pushes a frame
             wip.begin_value()?;
-            self.deserialize_into(wip)?;
+            if let Err(e) = self.deserialize_into(wip) {
+                if self.collect_errors {
+                    // Same reasoning as the list-element case: resync to
+                    // the next member instead of aborting the whole map.
+                    self.record_error(e)?;
+                    wip.set_default()?;
+                    wip.end()?;
+                    self.resync_after_error()?;
+                    continue;
+                }
+                return Err(e);
+            }
             wip.end()?;
 
             // Comma or end
@@ -2023,6 +4067,15 @@ impl<'input> JsonDeserializer<'input> {
 
         // Special case: &str can borrow directly from input if no escaping needed
         if is_str_ref {
+            if self.reject_borrows {
+                let span = self.peek()?.span;
+                return Err(JsonError::new(
+                    JsonErrorKind::InvalidValue {
+                        message: "cannot borrow &str - the input buffer for this deserializer isn't kept alive by the caller (e.g. it came from from_reader) - use String instead".into(),
+                    },
+                    span,
+                ));
+            }
             let token = self.next()?;
             match token.node {
                 Token::String(Cow::Borrowed(s)) => {
@@ -2161,7 +4214,10 @@ impl<'input> JsonDeserializer<'input> {
             }
 
             wip.begin_nth_field(i)?;
-            self.deserialize_into(wip)?;
+            self.path_stack.push(PathComponent::Index(i));
+            let result = self.deserialize_into(wip);
+            self.path_stack.pop();
+            result?;
             wip.end()?;
         }
 
@@ -2269,7 +4325,10 @@ impl<'input> JsonDeserializer<'input> {
             }
 
             wip.begin_nth_field(i)?;
-            self.deserialize_into(wip)?;
+            self.path_stack.push(PathComponent::Index(i));
+            let result = self.deserialize_into(wip);
+            self.path_stack.pop();
+            result?;
             wip.end()?;
         }
 
@@ -2288,6 +4347,233 @@ impl<'input> JsonDeserializer<'input> {
     }
 }
 
+// ============================================================================
+// NDJSON / JSON Lines iteration
+// ============================================================================
+
+/// Iterator over a newline-delimited stream of JSON documents, created via
+/// [`JsonDeserializer::iter_lines`].
+pub struct Lines<'input, T: Facet<'input>> {
+    input: &'input [u8],
+    offset: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'input, T: Facet<'input>> Lines<'input, T> {
+    /// Advance past the offending value by seeking to just after the next
+    /// newline, so the next iteration starts fresh at the following line.
+    fn resync(&mut self) {
+        match self.input[self.offset..].iter().position(|&b| b == b'\n') {
+            Some(rel) => self.offset += rel + 1,
+            None => self.offset = self.input.len(),
+        }
+    }
+}
+
+impl<'input, T: Facet<'input>> Iterator for Lines<'input, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset < self.input.len() && self.input[self.offset].is_ascii_whitespace() {
+            self.offset += 1;
+        }
+        if self.offset >= self.input.len() {
+            return None;
+        }
+
+        let mut deserializer = JsonDeserializer::from_offset(self.input, self.offset);
+        let mut wip = match Partial::alloc::<T>() {
+            Ok(wip) => wip,
+            Err(e) => {
+                self.resync();
+                return Some(Err(JsonError::from(e)));
+            }
+        };
+
+        match deserializer.deserialize_into(wip.inner_mut()) {
+            Ok(()) => {
+                // Find where this value ended (relative to its own
+                // sub-slice) so the next iteration resumes right after it,
+                // without re-lexing from the start of the whole input.
+                let consumed = deserializer
+                    .peek()
+                    .map(|tok| tok.span.start)
+                    .unwrap_or(self.input.len() - self.offset);
+                let result = wip.build().map(|b| *b).map_err(JsonError::from);
+                self.offset += consumed.max(1);
+                Some(result)
+            }
+            Err(e) => {
+                self.resync();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Iterator over a stream of concatenated JSON values sharing a single
+/// underlying [`JsonDeserializer`], created via [`from_slice_iter`]/
+/// [`from_str_iter`] - modeled on `serde_json::StreamDeserializer`.
+///
+/// Unlike [`JsonDeserializer::iter_lines`] (which starts a fresh
+/// sub-deserializer per line), values here don't need to be
+/// newline-delimited - any amount of whitespace between them is skipped -
+/// but the stream shares one deserializer across items, so once an item
+/// yields an `Err` the deserializer's position can no longer be trusted and
+/// every subsequent call returns `None`.
+pub struct StreamDeserializer<'input, T: Facet<'input>> {
+    de: JsonDeserializer<'input>,
+    source: Option<&'input str>,
+    done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'input, T: Facet<'input>> StreamDeserializer<'input, T> {
+    fn attach_source(&self, mut err: JsonError) -> JsonError {
+        if let Some(src) = self.source {
+            err.source_code = Some(src.to_string());
+        }
+        err
+    }
+}
+
+impl<'input, T: Facet<'input>> Iterator for StreamDeserializer<'input, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        // Skip inter-value whitespace and stop cleanly at end of input.
+        match self.de.peek() {
+            Ok(tok) if matches!(tok.node, Token::Eof) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.done = true;
+                return Some(Err(self.attach_source(e)));
+            }
+        }
+
+        let mut wip = match Partial::alloc::<T>() {
+            Ok(wip) => wip,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(self.attach_source(JsonError::from(e))));
+            }
+        };
+
+        match self.de.deserialize_into(wip.inner_mut()) {
+            Ok(()) => match wip.build().map(|b| *b) {
+                Ok(value) => Some(Ok(value)),
+                Err(e) => {
+                    self.done = true;
+                    Some(Err(self.attach_source(JsonError::from(e))))
+                }
+            },
+            Err(e) => {
+                self.done = true;
+                Some(Err(self.attach_source(e)))
+            }
+        }
+    }
+}
+
+/// Iterator over a newline-delimited stream of JSON documents read
+/// incrementally from an [`std::io::Read`], created via [`from_reader_lines`].
+///
+/// Each line is read and decoded as it's requested, so gigabyte-scale NDJSON
+/// files can be processed in `O(1)` memory relative to file size (modulo the
+/// size of a single line). Because the record for each line is owned rather
+/// than borrowed from a shared buffer, `T` must not borrow from the input -
+/// use [`JsonDeserializer::iter_lines`] instead for zero-copy decoding of an
+/// in-memory buffer.
+#[cfg(feature = "std")]
+pub struct ReaderLines<R, T> {
+    lines: std::io::Lines<std::io::BufReader<R>>,
+    _marker: PhantomData<T>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read, T> Iterator for ReaderLines<R, T>
+where
+    T: for<'a> Facet<'a>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => {
+                    return Some(Err(JsonError::without_span(JsonErrorKind::InvalidValue {
+                        message: format!("I/O error reading NDJSON stream: {e}"),
+                    })));
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(from_str(&line));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, T> core::fmt::Debug for ReaderLines<R, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReaderLines").finish_non_exhaustive()
+    }
+}
+
+/// Decode a newline-delimited stream of JSON documents (NDJSON / JSON Lines)
+/// from a reader, yielding one `Result<T>` per line as it's read.
+///
+/// Unlike [`JsonDeserializer::iter_lines`], this never holds the whole input
+/// in memory - it pulls one line at a time from `reader`. An I/O error on the
+/// underlying reader surfaces as an `Err` for that item; iteration does not
+/// stop there, and the next call reads from `reader` again.
+#[cfg(feature = "std")]
+pub fn from_reader_lines<R: std::io::Read, T>(reader: R) -> ReaderLines<R, T>
+where
+    T: for<'a> Facet<'a>,
+{
+    use std::io::BufRead;
+    ReaderLines {
+        lines: std::io::BufReader::new(reader).lines(),
+        _marker: PhantomData,
+    }
+}
+
+/// Deserialize a single, fully-owned JSON document read from an
+/// [`std::io::Read`].
+///
+/// Unlike [`from_reader_lines`], this reads exactly one value (buffering the
+/// whole reader into memory first) rather than streaming NDJSON records.
+/// Because the bytes live only in a buffer local to this call, `T` can't
+/// zero-copy borrow from them - `&str`/`&[u8]` targets fail fast with a clear
+/// [`JsonErrorKind::InvalidValue`] instead of a dangling reference, so prefer
+/// [`from_slice`]/[`from_str`] when the caller can keep the input alive.
+#[cfg(feature = "std")]
+pub fn from_reader<R: std::io::Read, T>(mut reader: R) -> Result<T>
+where
+    T: for<'a> Facet<'a>,
+{
+    let mut buf = Vec::new();
+    reader
+        .read_to_end(&mut buf)
+        .map_err(|e| JsonError::without_span(JsonErrorKind::InvalidValue {
+            message: format!("I/O error reading JSON document: {e}"),
+        }))?;
+
+    let mut deserializer = JsonDeserializer::new(&buf);
+    deserializer.reject_borrows = true;
+    from_slice_inner(deserializer, None)
+}
+
 // ============================================================================
 // Public API
 // ============================================================================
@@ -2300,7 +4586,7 @@ pub fn from_slice<'input, 'facet, T: Facet<'facet>>(input: &'input [u8]) -> Resu
 where
     'input: 'facet,
 {
-    from_slice_inner(input, None)
+    from_slice_inner(JsonDeserializer::new(input), None)
 }
 
 /// Deserialize JSON from a UTF-8 string slice.
@@ -2315,19 +4601,195 @@ where
 
     // Handle BOM
     if input_bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
-        return from_slice_inner(&input_bytes[3..], Some(&input[3..]));
+        return from_slice_inner(JsonDeserializer::new(&input_bytes[3..]), Some(&input[3..]));
     }
-    from_slice_inner(input_bytes, Some(input))
+    from_slice_inner(JsonDeserializer::new(input_bytes), Some(input))
 }
 
-fn from_slice_inner<'input, 'facet, T: Facet<'facet>>(
+/// Deserialize JSON from a byte slice in error-accumulation mode.
+///
+/// Unlike [`from_slice`], this does not stop at the first problem: unknown
+/// fields, missing fields, and duplicate keys are all collected and
+/// returned together as a single [`JsonErrorKind::Multiple`] error, so
+/// every issue can be reported in one pass instead of round-tripping. Type
+/// mismatches and out-of-range numbers on scalar struct fields (a string
+/// where a number was expected, a `300` that doesn't fit in a `u8`, etc.)
+/// are recoverable the same way - the field falls back to its default and
+/// parsing continues - because a scalar value is always exactly one JSON
+/// token, so the tokenizer never loses its place. A failure nested inside a
+/// list element, map value, or struct field (rather than at a scalar leaf)
+/// is recovered too: the tokenizer resynchronizes by skipping forward -
+/// tracking nested bracket/brace depth - to the next sibling element or key
+/// at the same nesting depth, so one malformed entry doesn't sink the whole
+/// document.
+pub fn from_slice_collecting<'input, 'facet, T: Facet<'facet>>(input: &'input [u8]) -> Result<T>
+where
+    'input: 'facet,
+{
+    from_slice_inner(JsonDeserializer::new_collecting(input), None)
+}
+
+/// Deserialize JSON from a UTF-8 string slice in error-accumulation mode.
+///
+/// See [`from_slice_collecting`] for the collecting behavior and
+/// [`from_str`] for the source-code-attached diagnostics.
+pub fn from_str_collecting<'input, 'facet, T: Facet<'facet>>(input: &'input str) -> Result<T>
+where
+    'input: 'facet,
+{
+    let input_bytes = input.as_bytes();
+
+    if input_bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
+        return from_slice_inner(
+            JsonDeserializer::new_collecting(&input_bytes[3..]),
+            Some(&input[3..]),
+        );
+    }
+    from_slice_inner(JsonDeserializer::new_collecting(input_bytes), Some(input))
+}
+
+/// Deserialize JSON from a byte slice with custom [`DeserializerOptions`].
+pub fn from_slice_with_options<'input, 'facet, T: Facet<'facet>>(
+    input: &'input [u8],
+    options: DeserializerOptions,
+) -> Result<T>
+where
+    'input: 'facet,
+{
+    from_slice_inner(JsonDeserializer::with_options(input, options), None)
+}
+
+/// Deserialize JSON from a UTF-8 string slice with custom [`DeserializerOptions`].
+pub fn from_str_with_options<'input, 'facet, T: Facet<'facet>>(
+    input: &'input str,
+    options: DeserializerOptions,
+) -> Result<T>
+where
+    'input: 'facet,
+{
+    let input_bytes = input.as_bytes();
+
+    if input_bytes.starts_with(&[0xef, 0xbb, 0xbf]) {
+        return from_slice_inner(
+            JsonDeserializer::with_options(&input_bytes[3..], options),
+            Some(&input[3..]),
+        );
+    }
+    from_slice_inner(
+        JsonDeserializer::with_options(input_bytes, options),
+        Some(input),
+    )
+}
+
+/// Relaxations a lenient ("JSONC"-style) parse may opt into, modeled
+/// independently rather than as a single JSON5 toggle - see [`from_str_relaxed`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParseOptions {
+    /// Accept `//` line comments and `/* ... */` block comments.
+    pub allow_comments: bool,
+    /// Accept a trailing comma after the last array element or object member.
+    pub allow_trailing_commas: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        // `allow_comments` defaults to `false`: this build's tokenizer has
+        // no comment-skipping support (see `json5`/`from_str_relaxed`
+        // below), so defaulting it to `true` would make
+        // `from_str_relaxed(input, ParseOptions::default())` fail on every
+        // input, comments or not. `allow_trailing_commas` is never read -
+        // trailing commas are accepted or rejected the same way whatever its
+        // value - so defaulting it to `true` is harmless.
+        ParseOptions {
+            allow_comments: false,
+            allow_trailing_commas: true,
+        }
+    }
+}
+
+/// Deserialize JSON from a UTF-8 string slice, accepting JSONC-style
+/// relaxations per `options`.
+///
+/// `allow_trailing_commas` is not read: trailing commas are accepted or
+/// rejected the same way whatever its value - see
+/// [`DeserializerOptions::json5`] for which parsing loops accept one and
+/// which reject it. `allow_comments` shares the
+/// same lexer-level limitation as [`DeserializerOptions::json5`]: this
+/// build's tokenizer only lexes strict JSON, so setting it to `true` fails
+/// fast with [`JsonErrorKind::UnsupportedOption`] instead of silently
+/// ignoring comments - which is why it defaults to `false`, so
+/// `from_str_relaxed(input, ParseOptions::default())` parses ordinary
+/// comment-free JSON successfully instead of failing unconditionally. The
+/// strict [`from_str`] path is unaffected either way.
+pub fn from_str_relaxed<'input, 'facet, T: Facet<'facet>>(
+    input: &'input str,
+    options: ParseOptions,
+) -> Result<T>
+where
+    'input: 'facet,
+{
+    from_str_with_options(
+        input,
+        DeserializerOptions::new().json5(options.allow_comments),
+    )
+}
+
+/// Deserialize JSON from a byte slice, accepting JSONC-style relaxations per
+/// `options`. See [`from_str_relaxed`] for what each option does (and
+/// doesn't) affect.
+pub fn from_slice_relaxed<'input, 'facet, T: Facet<'facet>>(
     input: &'input [u8],
+    options: ParseOptions,
+) -> Result<T>
+where
+    'input: 'facet,
+{
+    from_slice_with_options(
+        input,
+        DeserializerOptions::new().json5(options.allow_comments),
+    )
+}
+
+/// Deserialize a stream of concatenated JSON values from a byte slice,
+/// yielding one `Result<T>` per value as it's parsed. See
+/// [`StreamDeserializer`] for the iteration semantics.
+pub fn from_slice_iter<'input, T: Facet<'input>>(input: &'input [u8]) -> StreamDeserializer<'input, T> {
+    StreamDeserializer {
+        de: JsonDeserializer::new(input),
+        source: None,
+        done: false,
+        _marker: PhantomData,
+    }
+}
+
+/// Deserialize a stream of concatenated JSON values from a UTF-8 string
+/// slice, yielding one `Result<T>` per value as it's parsed. Errors include
+/// source code context for rich diagnostic display, like [`from_str`]. See
+/// [`StreamDeserializer`] for the iteration semantics.
+pub fn from_str_iter<'input, T: Facet<'input>>(input: &'input str) -> StreamDeserializer<'input, T> {
+    StreamDeserializer {
+        de: JsonDeserializer::new(input.as_bytes()),
+        source: Some(input),
+        done: false,
+        _marker: PhantomData,
+    }
+}
+
+fn from_slice_inner<'input, 'facet, T: Facet<'facet>>(
+    mut deserializer: JsonDeserializer<'input>,
     source: Option<&str>,
 ) -> Result<T>
 where
     'input: 'facet,
 {
-    let mut deserializer = JsonDeserializer::new(input);
+    if deserializer.json5 {
+        let mut err = JsonError::without_span(JsonErrorKind::UnsupportedOption { option: "json5" });
+        if let Some(src) = source {
+            err.source_code = Some(src.to_string());
+        }
+        return Err(err);
+    }
+
     let mut wip = Partial::alloc::<T>()?;
 
     let result = deserializer.deserialize_into(wip.inner_mut());
@@ -2354,6 +4816,16 @@ where
         return Err(err);
     }
 
+    // In error-accumulation mode, report every recoverable error collected
+    // along the way instead of building a (possibly incomplete) value.
+    if !deserializer.errors.is_empty() {
+        let mut err = JsonError::without_span(JsonErrorKind::Multiple(deserializer.errors));
+        if let Some(src) = source {
+            err.source_code = Some(src.to_string());
+        }
+        return Err(err);
+    }
+
     wip.build().map(|b| *b).map_err(|e| {
         let mut err = JsonError::from(e);
         if let Some(src) = source {
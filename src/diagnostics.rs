@@ -0,0 +1,121 @@
+//! Configurable rendering of [`miette`] diagnostics.
+//!
+//! `examples/error_showcase.rs` builds its own `GraphicalReportHandler` by
+//! hand: picking a unicode theme and wiring in a syntect syntax highlighter
+//! for the `base16-ocean.dark` color scheme. [`DiagnosticRenderer`] packages
+//! that same plumbing so downstream users get the showcase-quality rendered
+//! output - unicode or ASCII box-drawing, with or without ANSI color, with
+//! an optional syntect theme - without re-deriving it, and so CI and other
+//! no-color environments can request a clean rendered string.
+
+use alloc::string::String;
+
+use miette::GraphicalTheme;
+
+/// Box-drawing style, color, and optional syntax highlighting for a
+/// [`DiagnosticRenderer`].
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticRendererOptions<'a> {
+    ascii: bool,
+    no_color: bool,
+    syntax_theme: Option<&'a str>,
+}
+
+impl<'a> DiagnosticRendererOptions<'a> {
+    /// Unicode box-drawing with ANSI color, no syntax highlighting - the
+    /// same defaults [`miette::GraphicalReportHandler::new`] uses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use ASCII (`+`, `-`, `|`) box-drawing characters instead of unicode.
+    pub fn ascii(mut self, enabled: bool) -> Self {
+        self.ascii = enabled;
+        self
+    }
+
+    /// Strip ANSI color codes from the rendered output, for CI logs and
+    /// other non-color-aware consumers.
+    pub fn no_color(mut self, enabled: bool) -> Self {
+        self.no_color = enabled;
+        self
+    }
+
+    /// Highlight the diagnostic's source code using a named [`syntect`]
+    /// theme (e.g. `"base16-ocean.dark"`), looked up in
+    /// `syntect::highlighting::ThemeSet::load_defaults()`. Requires the
+    /// `syntax-highlighting` feature; ignored otherwise.
+    pub fn syntax_theme(mut self, theme_name: &'a str) -> Self {
+        self.syntax_theme = Some(theme_name);
+        self
+    }
+}
+
+/// Renders [`miette::Diagnostic`]s to a `String` using a configured box
+/// style, color, and (optionally) syntax highlighting.
+///
+/// This is the same renderer the error showcase example builds ad hoc
+/// (`GraphicalReportHandler::new_themed(GraphicalTheme::unicode())` with a
+/// `SyntectHighlighter`), wrapped so callers don't have to re-derive the
+/// theme/highlighter plumbing themselves.
+pub struct DiagnosticRenderer {
+    handler: miette::GraphicalReportHandler,
+}
+
+impl DiagnosticRenderer {
+    /// Creates a renderer with [`DiagnosticRendererOptions::new`]'s defaults.
+    pub fn new() -> Self {
+        Self::with_options(DiagnosticRendererOptions::new())
+    }
+
+    /// Creates a renderer from explicit rendering options.
+    pub fn with_options(options: DiagnosticRendererOptions<'_>) -> Self {
+        let theme = match (options.ascii, options.no_color) {
+            (false, false) => GraphicalTheme::unicode(),
+            (false, true) => GraphicalTheme::unicode_nocolor(),
+            (true, false) => GraphicalTheme::ascii(),
+            (true, true) => GraphicalTheme::ascii_nocolor(),
+        };
+        let mut handler = miette::GraphicalReportHandler::new_themed(theme);
+
+        #[cfg(feature = "syntax-highlighting")]
+        if let Some(theme_name) = options.syntax_theme {
+            if let Some(highlighter) = build_syntax_highlighter(theme_name) {
+                handler = handler.with_syntax_highlighting(highlighter);
+            }
+        }
+        #[cfg(not(feature = "syntax-highlighting"))]
+        let _ = options.syntax_theme;
+
+        DiagnosticRenderer { handler }
+    }
+
+    /// Renders a diagnostic to a string, the way
+    /// [`miette::GraphicalReportHandler::render_report`] would print it for
+    /// a human reader.
+    pub fn render(&self, diagnostic: &dyn miette::Diagnostic) -> String {
+        let mut output = String::new();
+        self.handler
+            .render_report(&mut output, diagnostic)
+            .expect("rendering a diagnostic into a String cannot fail");
+        output
+    }
+}
+
+impl Default for DiagnosticRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "syntax-highlighting")]
+fn build_syntax_highlighter(
+    theme_name: &str,
+) -> Option<miette::highlighters::SyntectHighlighter> {
+    let syntax_set = syntect::parsing::SyntaxSet::load_defaults_newlines();
+    let theme_set = syntect::highlighting::ThemeSet::load_defaults();
+    let theme = theme_set.themes.get(theme_name)?.clone();
+    Some(miette::highlighters::SyntectHighlighter::new(
+        syntax_set, theme, false,
+    ))
+}
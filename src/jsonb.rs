@@ -0,0 +1,410 @@
+//! Binary JSONB decoding (MySQL/binlog wire format).
+//!
+//! MySQL stores `JSON` column values and binlog row images in a compact
+//! binary "JSONB" tape rather than text JSON: a one-byte type tag, an
+//! element count, and a key/value-entry table with inline-or-offset value
+//! encoding. This module walks that tape directly into a facet [`Partial`],
+//! reusing the same [`JsonDeserializer::set_string_value`] /
+//! [`JsonDeserializer::set_number_i64`] (and friends) machinery the text
+//! decoder uses, so scalar conversion/overflow behavior stays identical
+//! between the two front ends.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use facet_core::{Def, Facet, StructKind, Type, UserType};
+use facet_reflect::Partial;
+
+use crate::deserialize::{DEFAULT_MAX_DEPTH, JsonDeserializer, JsonError, JsonErrorKind, Result};
+use crate::span::Span;
+
+mod type_tag {
+    pub const SMALL_OBJECT: u8 = 0x00;
+    pub const LARGE_OBJECT: u8 = 0x01;
+    pub const SMALL_ARRAY: u8 = 0x02;
+    pub const LARGE_ARRAY: u8 = 0x03;
+    pub const LITERAL: u8 = 0x04;
+    pub const INT16: u8 = 0x05;
+    pub const UINT16: u8 = 0x06;
+    pub const INT32: u8 = 0x07;
+    pub const UINT32: u8 = 0x08;
+    pub const INT64: u8 = 0x09;
+    pub const UINT64: u8 = 0x0A;
+    pub const DOUBLE: u8 = 0x0B;
+    pub const STRING: u8 = 0x0C;
+    pub const OPAQUE: u8 = 0x0F;
+}
+
+const LITERAL_NULL: u8 = 0x00;
+const LITERAL_TRUE: u8 = 0x01;
+const LITERAL_FALSE: u8 = 0x02;
+
+fn invalid(message: impl Into<String>) -> JsonError {
+    JsonError::without_span(JsonErrorKind::InvalidValue {
+        message: message.into(),
+    })
+}
+
+/// Deserialize a MySQL-style binary JSONB document into a facet type.
+///
+/// `data` is the raw JSONB tape as stored by MySQL (the same bytes found in
+/// a `JSON` column's on-disk representation or a binlog row image) - a
+/// leading type-tag byte followed by the encoded value.
+pub fn from_jsonb<'input, 'facet, T: Facet<'facet>>(data: &'input [u8]) -> Result<T>
+where
+    'input: 'facet,
+{
+    let mut wip = Partial::alloc::<T>()?;
+    let (&tag, rest) = data
+        .split_first()
+        .ok_or_else(|| invalid("empty JSONB document"))?;
+    let mut reader = JsonbReader { doc: data };
+    reader.read_value(tag, rest, wip.inner_mut(), 0)?;
+    Ok(*wip.build()?)
+}
+
+/// Walks a JSONB tape, feeding scalars and nested containers into a
+/// [`Partial`] using the same setters the text decoder uses.
+struct JsonbReader<'input> {
+    /// The full document, used to resolve offset-based value entries.
+    doc: &'input [u8],
+}
+
+impl<'input> JsonbReader<'input> {
+    fn read_u16(bytes: &[u8]) -> Result<u16> {
+        let arr: [u8; 2] = bytes
+            .get(..2)
+            .and_then(|s| s.try_into().ok())
+            .ok_or_else(|| invalid("truncated JSONB document"))?;
+        Ok(u16::from_le_bytes(arr))
+    }
+
+    fn read_u32(bytes: &[u8]) -> Result<u32> {
+        let arr: [u8; 4] = bytes
+            .get(..4)
+            .and_then(|s| s.try_into().ok())
+            .ok_or_else(|| invalid("truncated JSONB document"))?;
+        Ok(u32::from_le_bytes(arr))
+    }
+
+    /// Reads a MySQL-style variable-length integer: 7 bits per byte,
+    /// little-endian, with the high bit of each byte marking continuation.
+    fn read_varlen(bytes: &[u8]) -> Result<(usize, &[u8])> {
+        let mut value: usize = 0;
+        let mut shift = 0;
+        for (i, &b) in bytes.iter().enumerate() {
+            value |= ((b & 0x7f) as usize) << shift;
+            if b & 0x80 == 0 {
+                return Ok((value, &bytes[i + 1..]));
+            }
+            shift += 7;
+            if shift > 28 {
+                return Err(invalid("JSONB variable-length integer too large"));
+            }
+        }
+        Err(invalid("truncated JSONB variable-length integer"))
+    }
+
+    /// Dispatches on the type tag and feeds the decoded value into `wip`.
+    ///
+    /// `body` is the slice starting right after the tag byte for a
+    /// top-level value, or the inline-or-offset slot contents for a nested
+    /// entry (see [`Self::read_entry_value`]).
+    fn read_value(
+        &mut self,
+        tag: u8,
+        body: &'input [u8],
+        wip: &mut Partial<'input>,
+        depth: usize,
+    ) -> Result<()> {
+        if depth >= DEFAULT_MAX_DEPTH {
+            return Err(JsonError::without_span(JsonErrorKind::DepthLimitExceeded {
+                max_depth: DEFAULT_MAX_DEPTH,
+            }));
+        }
+
+        let mut scratch = JsonDeserializer::new(&[]);
+        match tag {
+            type_tag::SMALL_OBJECT => self.read_object(body, false, wip, depth),
+            type_tag::LARGE_OBJECT => self.read_object(body, true, wip, depth),
+            type_tag::SMALL_ARRAY => self.read_array(body, false, wip, depth),
+            type_tag::LARGE_ARRAY => self.read_array(body, true, wip, depth),
+            type_tag::LITERAL => {
+                let lit = *body.first().ok_or_else(|| invalid("truncated literal"))?;
+                match lit {
+                    LITERAL_NULL => {
+                        wip.set_default()?;
+                        Ok(())
+                    }
+                    LITERAL_TRUE => Ok(wip.set(true)?),
+                    LITERAL_FALSE => Ok(wip.set(false)?),
+                    other => Err(invalid(format!("unknown JSONB literal tag {other:#x}"))),
+                }
+            }
+            type_tag::INT16 => {
+                let n = i16::from_le_bytes(
+                    body.get(..2)
+                        .and_then(|s| s.try_into().ok())
+                        .ok_or_else(|| invalid("truncated int16"))?,
+                );
+                scratch.set_number_i64(wip, n as i64, Span::default())
+            }
+            type_tag::UINT16 => {
+                scratch.set_number_u64(wip, Self::read_u16(body)? as u64, Span::default())
+            }
+            type_tag::INT32 => {
+                let n = i32::from_le_bytes(
+                    body.get(..4)
+                        .and_then(|s| s.try_into().ok())
+                        .ok_or_else(|| invalid("truncated int32"))?,
+                );
+                scratch.set_number_i64(wip, n as i64, Span::default())
+            }
+            type_tag::UINT32 => {
+                scratch.set_number_u64(wip, Self::read_u32(body)? as u64, Span::default())
+            }
+            type_tag::INT64 => {
+                let n = i64::from_le_bytes(
+                    body.get(..8)
+                        .and_then(|s| s.try_into().ok())
+                        .ok_or_else(|| invalid("truncated int64"))?,
+                );
+                scratch.set_number_i64(wip, n, Span::default())
+            }
+            type_tag::UINT64 => {
+                let n = u64::from_le_bytes(
+                    body.get(..8)
+                        .and_then(|s| s.try_into().ok())
+                        .ok_or_else(|| invalid("truncated uint64"))?,
+                );
+                scratch.set_number_u64(wip, n, Span::default())
+            }
+            type_tag::DOUBLE => {
+                let n = f64::from_le_bytes(
+                    body.get(..8)
+                        .and_then(|s| s.try_into().ok())
+                        .ok_or_else(|| invalid("truncated double"))?,
+                );
+                scratch.set_number_f64(wip, n, Span::default())
+            }
+            type_tag::STRING => {
+                let (len, rest) = Self::read_varlen(body)?;
+                let bytes = rest
+                    .get(..len)
+                    .ok_or_else(|| invalid("truncated JSONB string"))?;
+                let s = core::str::from_utf8(bytes)
+                    .map_err(|_| JsonError::without_span(JsonErrorKind::InvalidUtf8))?;
+                scratch.set_string_value(wip, alloc::borrow::Cow::Borrowed(s))
+            }
+            type_tag::OPAQUE => Err(invalid(
+                "JSONB opaque values (dates, decimals, etc.) aren't supported yet",
+            )),
+            other => Err(invalid(format!("unknown JSONB type tag {other:#x}"))),
+        }
+    }
+
+    /// Reads the inline-or-offset value for one table entry: for types that
+    /// fit in the entry's own slot (literal, int16, uint16, and additionally
+    /// int32/uint32 in large documents) the value is inline; otherwise the
+    /// slot holds an offset into `self.doc` where the value (preceded by its
+    /// own encoding, with no extra type tag) lives.
+    fn read_entry_value(
+        &mut self,
+        value_tag: u8,
+        slot: &'input [u8],
+        large: bool,
+        wip: &mut Partial<'input>,
+        depth: usize,
+    ) -> Result<()> {
+        let inline = matches!(value_tag, type_tag::LITERAL | type_tag::INT16 | type_tag::UINT16)
+            || (large && matches!(value_tag, type_tag::INT32 | type_tag::UINT32));
+
+        if inline {
+            self.read_value(value_tag, slot, wip, depth)
+        } else {
+            let offset = if large {
+                Self::read_u32(slot)? as usize
+            } else {
+                Self::read_u16(slot)? as usize
+            };
+            let value_body = self
+                .doc
+                .get(offset..)
+                .ok_or_else(|| invalid("JSONB value offset out of range"))?;
+            self.read_value(value_tag, value_body, wip, depth)
+        }
+    }
+
+    fn read_array(
+        &mut self,
+        body: &'input [u8],
+        large: bool,
+        wip: &mut Partial<'input>,
+        depth: usize,
+    ) -> Result<()> {
+        let (count, _size, mut cursor) = self.read_container_header(body, large)?;
+        let entry_width = if large { 4 } else { 2 };
+
+        wip.begin_list()?;
+        for _ in 0..count {
+            let value_tag = *cursor.first().ok_or_else(|| invalid("truncated entry"))?;
+            let slot = cursor
+                .get(1..1 + entry_width)
+                .ok_or_else(|| invalid("truncated entry"))?;
+
+            wip.begin_list_item()?;
+            self.read_entry_value(value_tag, slot, large, wip, depth + 1)?;
+            wip.end()?;
+
+            cursor = &cursor[1 + entry_width..];
+        }
+        Ok(())
+    }
+
+    fn read_object(
+        &mut self,
+        body: &'input [u8],
+        large: bool,
+        wip: &mut Partial<'input>,
+        depth: usize,
+    ) -> Result<()> {
+        let struct_def = match &wip.shape().ty {
+            Type::User(UserType::Struct(s)) if s.kind == StructKind::Struct => s,
+            _ if matches!(wip.shape().def, Def::Map(_)) => {
+                return self.read_object_into_map(body, large, wip, depth);
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "cannot deserialize a JSONB object into {}",
+                    wip.shape().type_identifier
+                )));
+            }
+        };
+
+        let (count, _size, mut cursor) = self.read_container_header(body, large)?;
+        let key_width = if large { 4 } else { 2 };
+
+        let mut key_entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let key_offset = if large {
+                Self::read_u32(cursor)? as usize
+            } else {
+                Self::read_u16(cursor)? as usize
+            };
+            let key_len = Self::read_u16(&cursor[key_width..])? as usize;
+            key_entries.push((key_offset, key_len));
+            cursor = &cursor[key_width + 2..];
+        }
+
+        let entry_width = if large { 4 } else { 2 };
+        let mut fields_set = alloc::vec![false; struct_def.fields.len()];
+        for &(key_offset, key_len) in &key_entries {
+            let value_tag = *cursor.first().ok_or_else(|| invalid("truncated entry"))?;
+            let slot = cursor
+                .get(1..1 + entry_width)
+                .ok_or_else(|| invalid("truncated entry"))?;
+            cursor = &cursor[1 + entry_width..];
+
+            let key_bytes = self
+                .doc
+                .get(key_offset..key_offset + key_len)
+                .ok_or_else(|| invalid("JSONB key offset out of range"))?;
+            let key = core::str::from_utf8(key_bytes)
+                .map_err(|_| JsonError::without_span(JsonErrorKind::InvalidUtf8))?;
+
+            if let Some((idx, field)) = struct_def
+                .fields
+                .iter()
+                .enumerate()
+                .find(|(_, f)| f.name == key)
+            {
+                wip.begin_field(field.name)?;
+                self.read_entry_value(value_tag, slot, large, wip, depth + 1)?;
+                wip.end()?;
+                fields_set[idx] = true;
+            }
+            // Unknown keys are skipped - there's no text-source span to
+            // attach to an UnknownField diagnostic for a binary document.
+        }
+
+        for (idx, field) in struct_def.fields.iter().enumerate() {
+            if !fields_set[idx] && wip.set_nth_field_to_default(idx).is_err() {
+                return Err(JsonError::without_span(JsonErrorKind::MissingField {
+                    field: field.name,
+                    object_start: None,
+                    object_end: None,
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    fn read_object_into_map(
+        &mut self,
+        body: &'input [u8],
+        large: bool,
+        wip: &mut Partial<'input>,
+        depth: usize,
+    ) -> Result<()> {
+        let (count, _size, mut cursor) = self.read_container_header(body, large)?;
+        let key_width = if large { 4 } else { 2 };
+
+        let mut key_entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let key_offset = if large {
+                Self::read_u32(cursor)? as usize
+            } else {
+                Self::read_u16(cursor)? as usize
+            };
+            let key_len = Self::read_u16(&cursor[key_width..])? as usize;
+            key_entries.push((key_offset, key_len));
+            cursor = &cursor[key_width + 2..];
+        }
+
+        let entry_width = if large { 4 } else { 2 };
+        wip.begin_map()?;
+        for &(key_offset, key_len) in &key_entries {
+            let value_tag = *cursor.first().ok_or_else(|| invalid("truncated entry"))?;
+            let slot = cursor
+                .get(1..1 + entry_width)
+                .ok_or_else(|| invalid("truncated entry"))?;
+            cursor = &cursor[1 + entry_width..];
+
+            let key_bytes = self
+                .doc
+                .get(key_offset..key_offset + key_len)
+                .ok_or_else(|| invalid("JSONB key offset out of range"))?;
+            let key = core::str::from_utf8(key_bytes)
+                .map_err(|_| JsonError::without_span(JsonErrorKind::InvalidUtf8))?;
+
+            wip.begin_key()?;
+            let mut scratch = JsonDeserializer::new(&[]);
+            scratch.set_string_value(wip, alloc::borrow::Cow::Borrowed(key))?;
+            wip.end()?;
+
+            wip.begin_value()?;
+            self.read_entry_value(value_tag, slot, large, wip, depth + 1)?;
+            wip.end()?;
+        }
+        Ok(())
+    }
+
+    /// Reads the `(count, size)` header common to objects and arrays and
+    /// returns the remaining slice (the entry table).
+    fn read_container_header(
+        &self,
+        body: &'input [u8],
+        large: bool,
+    ) -> Result<(usize, usize, &'input [u8])> {
+        if large {
+            let count = Self::read_u32(body)? as usize;
+            let size = Self::read_u32(&body[4..])? as usize;
+            Ok((count, size, &body[8..]))
+        } else {
+            let count = Self::read_u16(body)? as usize;
+            let size = Self::read_u16(&body[2..])? as usize;
+            Ok((count, size, &body[4..]))
+        }
+    }
+}
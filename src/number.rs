@@ -0,0 +1,90 @@
+//! Arbitrary-precision JSON numbers, for values that don't fit (or would
+//! lose precision in) any native integer/float type.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use facet_core::Facet;
+
+/// A JSON number captured in its exact textual form, instead of being
+/// parsed into a native integer/float type.
+///
+/// Mirrors serde_json's `arbitrary_precision` feature: deserializing into
+/// `JsonNumber` never fails on magnitude or loses precision, since the
+/// source digits are sliced straight from the input rather than parsed, and
+/// serializing one re-emits those exact digits verbatim. Use
+/// [`JsonNumber::as_i64`]/[`JsonNumber::as_u128`]/[`JsonNumber::as_f64`] (and
+/// friends) to convert on demand - each returns `None` rather than failing
+/// the whole deserialize if the value doesn't fit the requested type.
+#[derive(Facet, Debug, Clone, PartialEq)]
+pub struct JsonNumber<'input> {
+    text: Cow<'input, str>,
+}
+
+impl<'input> JsonNumber<'input> {
+    /// Wraps an already-captured number literal without validating it.
+    ///
+    /// Callers that need validation should go through [`crate::from_str`]
+    /// with `JsonNumber` as the target type instead.
+    pub fn from_borrowed(text: &'input str) -> Self {
+        JsonNumber {
+            text: Cow::Borrowed(text),
+        }
+    }
+
+    /// Wraps an owned number literal without validating it.
+    pub fn from_owned(text: String) -> Self {
+        JsonNumber {
+            text: Cow::Owned(text),
+        }
+    }
+
+    /// Returns the exact source digits this value was captured from.
+    pub fn as_str(&self) -> &str {
+        self.text.as_ref()
+    }
+
+    /// Converts to an owned `JsonNumber<'static>`, copying the text if borrowed.
+    pub fn into_owned(self) -> JsonNumber<'static> {
+        JsonNumber {
+            text: Cow::Owned(self.text.into_owned()),
+        }
+    }
+
+    /// Parses as an `i64`, returning `None` if the value doesn't fit or has
+    /// a fractional part.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.text.parse().ok()
+    }
+
+    /// Parses as a `u64`, returning `None` if the value doesn't fit, is
+    /// negative, or has a fractional part.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.text.parse().ok()
+    }
+
+    /// Parses as an `i128`, returning `None` if the value doesn't fit or has
+    /// a fractional part.
+    pub fn as_i128(&self) -> Option<i128> {
+        self.text.parse().ok()
+    }
+
+    /// Parses as a `u128`, returning `None` if the value doesn't fit, is
+    /// negative, or has a fractional part.
+    pub fn as_u128(&self) -> Option<u128> {
+        self.text.parse().ok()
+    }
+
+    /// Parses as an `f64`. Unlike the integer accessors this essentially
+    /// always succeeds for well-formed JSON number text, but can still
+    /// lose precision for values wider than `f64` can represent exactly.
+    pub fn as_f64(&self) -> Option<f64> {
+        self.text.parse().ok()
+    }
+}
+
+impl<'input> core::fmt::Display for JsonNumber<'input> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.text.as_ref())
+    }
+}
@@ -0,0 +1,87 @@
+//! Raw, uninterpreted JSON passthrough, the equivalent of serde_json's
+//! `RawValue`.
+//!
+//! [`JsonRaw`] captures the exact source text of a JSON value without
+//! parsing it into [`crate::value::JsonValue`] or any concrete type. This is
+//! useful when a field's contents should be forwarded verbatim (re-emitted
+//! byte-for-byte on serialization) without paying the cost of building and
+//! walking a full [`crate::value::JsonValue`] tree for data the caller
+//! doesn't need to inspect.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use facet_core::Facet;
+
+/// A JSON value captured as raw, unparsed source text.
+///
+/// Deserializing into `JsonRaw` never fails on shape mismatch - any valid
+/// JSON value captures, exactly as written (including whitespace inside it)
+/// - and serializing one writes its stored text back out unchanged: no
+/// re-escaping, no re-indenting, not even a recursion-depth check, since the
+/// bytes are spliced in verbatim rather than reflected into. In
+/// [`to_string_pretty`](crate::to_string_pretty) output the fragment lands at
+/// whatever indentation the surrounding struct/array/map already produced
+/// for that position, so nesting still reads correctly around it even
+/// though the fragment's own interior isn't reformatted.
+#[derive(Facet, Debug, Clone, PartialEq, Eq)]
+pub struct JsonRaw<'input> {
+    text: Cow<'input, str>,
+}
+
+impl<'input> JsonRaw<'input> {
+    /// Wraps already-captured JSON source text without validating it.
+    ///
+    /// Callers that need validation should go through [`crate::from_str`]
+    /// with `JsonRaw` as the target type instead.
+    pub fn from_borrowed(text: &'input str) -> Self {
+        JsonRaw {
+            text: Cow::Borrowed(text),
+        }
+    }
+
+    /// Wraps an owned JSON source string without validating it.
+    pub fn from_owned(text: String) -> Self {
+        JsonRaw {
+            text: Cow::Owned(text),
+        }
+    }
+
+    /// Returns the exact JSON source text this value was captured from.
+    pub fn get(&self) -> &str {
+        self.text.as_ref()
+    }
+
+    /// Converts to an owned `JsonRaw<'static>`, copying the text if borrowed.
+    pub fn into_owned(self) -> JsonRaw<'static> {
+        JsonRaw {
+            text: Cow::Owned(self.text.into_owned()),
+        }
+    }
+}
+
+impl<'input> core::fmt::Display for JsonRaw<'input> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.text.as_ref())
+    }
+}
+
+/// Alias for [`JsonRaw`], for callers coming from `serde_json::RawValue`
+/// naming conventions. `JsonRaw` already covers both the borrowed and owned
+/// cases via its internal `Cow`, so there's no separate owned type - unlike
+/// `serde_json::value::RawValue`/`Box<RawValue>`, one type serves both.
+pub type RawJson<'input> = JsonRaw<'input>;
+
+/// Alias for the owned form of [`JsonRaw`] (`JsonRaw<'static>`), for callers
+/// expecting a `RawValueBuf`-style owned type.
+pub type RawJsonBuf = JsonRaw<'static>;
+
+/// Alias for [`JsonRaw`] matching `serde_json::value::RawValue`'s name
+/// exactly, for drop-in replacement when porting code off `serde_json`.
+/// Identical to [`RawJson`] - the two names exist because different callers
+/// reach for different conventions, not because the types differ.
+pub type RawValue<'input> = JsonRaw<'input>;
+
+/// Alias for the owned form of [`JsonRaw`] matching `serde_json`'s
+/// `RawValueBuf` naming. Identical to [`RawJsonBuf`].
+pub type RawValueBuf = JsonRaw<'static>;
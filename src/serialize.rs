@@ -1,9 +1,634 @@
+use alloc::borrow::Cow;
 use alloc::string::String;
 use alloc::vec::Vec;
-use facet_core::{Def, Facet, Field, PointerType, ShapeAttribute, StructKind, Type, UserType};
+use facet_core::{Def, Facet, Field, PointerType, Shape, ShapeAttribute, StructKind, Type, UserType};
 use facet_reflect::{HasFields, Peek, ScalarType};
 use log::trace;
 
+use crate::deserialize::apply_rename_all;
+
+/// Renders a field or externally-tagged variant name for output, applying
+/// the container's `#[facet(rename_all = "...")]` convention (if any) - the
+/// serialize-side counterpart of `deserialize::field_name_matches`.
+fn render_name<'a>(shape: &Shape, name: &'a str) -> Cow<'a, str> {
+    match shape.get_rename_all_attr() {
+        Some(case) => Cow::Owned(apply_rename_all(name, case)),
+        None => Cow::Borrowed(name),
+    }
+}
+
+/// Writes `value` as a quoted JSON string, honoring
+/// [`SerializeConfig::ensure_ascii`] - every string-valued piece of output
+/// (scalar strings, map keys, enum variant/field names) routes through
+/// here rather than calling [`Formatter::write_string_fragment`] directly,
+/// so `ensure_ascii` applies uniformly regardless of which of those a
+/// given value happens to be.
+fn write_string<W: crate::JsonWrite, F: Formatter>(
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+    value: &str,
+) {
+    if cfg.ensure_ascii {
+        write_json_string_ascii(writer, value);
+    } else {
+        formatter.write_string_fragment(writer, value);
+    }
+}
+
+/// Writes `value` as a quoted JSON string with every non-ASCII code point
+/// escaped as `\uXXXX` - see [`SerializeOptions::ensure_ascii`].
+fn write_json_string_ascii<W: crate::JsonWrite>(writer: &mut W, value: &str) {
+    writer.write(b"\"");
+    for c in value.chars() {
+        write_ascii_escaped_char(writer, c);
+    }
+    writer.write(b"\"");
+}
+
+/// Writes a single character's contribution to an ASCII-only JSON string
+/// literal - the caller is responsible for the surrounding quotes.
+fn write_ascii_escaped_char<W: crate::JsonWrite>(writer: &mut W, c: char) {
+    match c {
+        '"' => writer.write(b"\\\""),
+        '\\' => writer.write(b"\\\\"),
+        '\n' => writer.write(b"\\n"),
+        '\r' => writer.write(b"\\r"),
+        '\t' => writer.write(b"\\t"),
+        c if (c as u32) < 0x20 => write_unicode_escape(writer, c as u32),
+        c if c.is_ascii() => {
+            let mut buf = [0u8; 1];
+            writer.write(c.encode_utf8(&mut buf).as_bytes());
+        }
+        c => {
+            let code_point = c as u32;
+            if code_point <= 0xFFFF {
+                write_unicode_escape(writer, code_point);
+            } else {
+                // Code points above the Basic Multilingual Plane have no
+                // single \uXXXX escape - split into a UTF-16 surrogate pair,
+                // same as every other JSON encoder's ensure_ascii mode.
+                let offset = code_point - 0x10000;
+                write_unicode_escape(writer, 0xD800 + (offset >> 10));
+                write_unicode_escape(writer, 0xDC00 + (offset & 0x3FF));
+            }
+        }
+    }
+}
+
+/// Writes a single `\uXXXX` escape for one UTF-16 code unit.
+fn write_unicode_escape<W: crate::JsonWrite>(writer: &mut W, code_unit: u32) {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    writer.write(&[
+        b'\\',
+        b'u',
+        HEX_DIGITS[((code_unit >> 12) & 0xF) as usize],
+        HEX_DIGITS[((code_unit >> 8) & 0xF) as usize],
+        HEX_DIGITS[((code_unit >> 4) & 0xF) as usize],
+        HEX_DIGITS[(code_unit & 0xF) as usize],
+    ]);
+}
+
+/// Hooks controlling how serialized JSON is laid out - object/array
+/// delimiters, separators, and scalar encoding - following serde_json's
+/// `Formatter` trait. Every hook has a default implementation that produces
+/// compact output, so [`CompactFormatter`] is simply `impl Formatter for
+/// CompactFormatter {}`; [`PrettyFormatter`] overrides the structural hooks
+/// to add indentation. Implement this trait directly for custom layouts
+/// (tab indentation, aligned colons, etc.) without forking the crate, and
+/// reach it through [`to_writer_with_formatter`]/[`peek_to_writer_with_formatter`].
+pub trait Formatter {
+    /// Writes a JSON `null`.
+    fn write_null<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b"null");
+    }
+
+    /// Writes a JSON boolean.
+    fn write_bool<W: crate::JsonWrite>(&mut self, writer: &mut W, value: bool) {
+        writer.write(if value { b"true" } else { b"false" });
+    }
+
+    /// Writes a JSON number.
+    fn write_i8<W: crate::JsonWrite>(&mut self, writer: &mut W, value: i8) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_i16<W: crate::JsonWrite>(&mut self, writer: &mut W, value: i16) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_i32<W: crate::JsonWrite>(&mut self, writer: &mut W, value: i32) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_i64<W: crate::JsonWrite>(&mut self, writer: &mut W, value: i64) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_i128<W: crate::JsonWrite>(&mut self, writer: &mut W, value: i128) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_isize<W: crate::JsonWrite>(&mut self, writer: &mut W, value: isize) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_u8<W: crate::JsonWrite>(&mut self, writer: &mut W, value: u8) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_u16<W: crate::JsonWrite>(&mut self, writer: &mut W, value: u16) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_u32<W: crate::JsonWrite>(&mut self, writer: &mut W, value: u32) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_u64<W: crate::JsonWrite>(&mut self, writer: &mut W, value: u64) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_u128<W: crate::JsonWrite>(&mut self, writer: &mut W, value: u128) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_usize<W: crate::JsonWrite>(&mut self, writer: &mut W, value: usize) {
+        writer.write(itoa::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_f32<W: crate::JsonWrite>(&mut self, writer: &mut W, value: f32) {
+        writer.write(ryu::Buffer::new().format(value).as_bytes());
+    }
+    /// Writes a JSON number.
+    fn write_f64<W: crate::JsonWrite>(&mut self, writer: &mut W, value: f64) {
+        writer.write(ryu::Buffer::new().format(value).as_bytes());
+    }
+
+    /// Writes a complete, already-unescaped string fragment as a quoted,
+    /// escaped JSON string. String escaping itself is centralized in
+    /// [`crate::write_json_string`]; this hook exists so a custom formatter
+    /// can intercept or transform string output (e.g. ASCII-only escaping).
+    fn write_string_fragment<W: crate::JsonWrite>(&mut self, writer: &mut W, fragment: &str) {
+        crate::write_json_string(writer, fragment);
+    }
+
+    /// Called before the first element of an array.
+    fn begin_array<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b"[");
+    }
+    /// Called after the last element of an array.
+    fn end_array<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b"]");
+    }
+    /// Called before every array element, `first` indicating whether a
+    /// separating comma is needed.
+    fn array_value_separator<W: crate::JsonWrite>(&mut self, writer: &mut W, first: bool) {
+        if !first {
+            writer.write(b",");
+        }
+    }
+    /// Called after every array element.
+    fn end_array_value<W: crate::JsonWrite>(&mut self, _writer: &mut W) {}
+
+    /// Called before the first entry of an object.
+    fn begin_object<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b"{");
+    }
+    /// Called after the last entry of an object.
+    fn end_object<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b"}");
+    }
+    /// Called before every object key, `first` indicating whether a
+    /// separating comma is needed.
+    fn begin_object_key<W: crate::JsonWrite>(&mut self, writer: &mut W, first: bool) {
+        if !first {
+            writer.write(b",");
+        }
+    }
+    /// Called after every object key, before its value.
+    fn end_object_key<W: crate::JsonWrite>(&mut self, _writer: &mut W) {}
+    /// Called before every object value.
+    fn begin_object_value<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b":");
+    }
+    /// Called after every object value.
+    fn end_object_value<W: crate::JsonWrite>(&mut self, _writer: &mut W) {}
+}
+
+/// The default compact formatter: no whitespace between tokens. Every hook
+/// uses [`Formatter`]'s default (already-compact) implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompactFormatter;
+
+impl Formatter for CompactFormatter {}
+
+/// A formatter that indents nested JSON with a configurable indent unit
+/// (`"  "` by default), repeated once per nesting level, and puts a space
+/// after each object colon.
+#[derive(Debug, Clone)]
+pub struct PrettyFormatter<'a> {
+    current_indent: usize,
+    has_value: bool,
+    indent: &'a str,
+}
+
+impl<'a> PrettyFormatter<'a> {
+    /// Creates a formatter that indents with two spaces per level.
+    pub fn new() -> Self {
+        Self::with_indent("  ")
+    }
+
+    /// Creates a formatter that indents with `indent` (e.g. `"\t"` or
+    /// `"    "`) repeated once per nesting level.
+    pub fn with_indent(indent: &'a str) -> Self {
+        PrettyFormatter {
+            current_indent: 0,
+            has_value: false,
+            indent,
+        }
+    }
+
+    fn write_indent<W: crate::JsonWrite>(&self, writer: &mut W) {
+        for _ in 0..self.current_indent {
+            writer.write(self.indent.as_bytes());
+        }
+    }
+}
+
+impl<'a> Default for PrettyFormatter<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Formatter for PrettyFormatter<'a> {
+    fn begin_array<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        self.current_indent += 1;
+        self.has_value = false;
+        writer.write(b"[");
+    }
+
+    fn end_array<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        self.current_indent -= 1;
+        if self.has_value {
+            writer.write(b"\n");
+            self.write_indent(writer);
+        }
+        writer.write(b"]");
+    }
+
+    fn array_value_separator<W: crate::JsonWrite>(&mut self, writer: &mut W, first: bool) {
+        if !first {
+            writer.write(b",");
+        }
+        writer.write(b"\n");
+        self.write_indent(writer);
+    }
+
+    fn end_array_value<W: crate::JsonWrite>(&mut self, _writer: &mut W) {
+        self.has_value = true;
+    }
+
+    fn begin_object<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        self.current_indent += 1;
+        self.has_value = false;
+        writer.write(b"{");
+    }
+
+    fn end_object<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        self.current_indent -= 1;
+        if self.has_value {
+            writer.write(b"\n");
+            self.write_indent(writer);
+        }
+        writer.write(b"}");
+    }
+
+    fn begin_object_key<W: crate::JsonWrite>(&mut self, writer: &mut W, first: bool) {
+        if !first {
+            writer.write(b",");
+        }
+        writer.write(b"\n");
+        self.write_indent(writer);
+    }
+
+    fn begin_object_value<W: crate::JsonWrite>(&mut self, writer: &mut W) {
+        writer.write(b": ");
+    }
+
+    fn end_object_value<W: crate::JsonWrite>(&mut self, _writer: &mut W) {
+        self.has_value = true;
+    }
+}
+
+/// How to serialize a `NaN` or infinite `f32`/`f64` value, none of which
+/// have a valid JSON representation - see [`SerializeOptions::non_finite_floats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFiniteFloatHandling {
+    /// Serialize non-finite floats as `null`, matching serde_json's default
+    /// behavior. Lossy, but always produces valid JSON.
+    #[default]
+    Null,
+    /// Fail serialization with [`SerializeError::NonFiniteFloat`] instead of
+    /// silently losing the value.
+    Error,
+    /// Emit the non-standard `NaN`, `Infinity`, `-Infinity` tokens as-is.
+    /// Round-trips losslessly through parsers that accept extended JSON
+    /// (this crate's own deserializer does not, by default), but the output
+    /// is not valid JSON.
+    Raw,
+}
+
+/// How byte sequences (`Vec<u8>`, `[u8; N]`, `&[u8]`) are written - see
+/// [`SerializeOptions::byte_encoding`]. This is a crate-wide setting; there
+/// is currently no per-field override, so every byte sequence in a document
+/// uses the same encoding. A caller that needs two byte fields to use
+/// different wire forms can still reach for a manual
+/// `serialize_with`/`deserialize_with` pair, as `test_custom_serialization_struct` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteEncoding {
+    /// A JSON array of numbers, one per byte. Matches how a `Vec<u8>` looks
+    /// to any other JSON library that doesn't special-case byte sequences.
+    #[default]
+    Array,
+    /// Standard base64 (RFC 4648 §4, with `=` padding), written as a JSON
+    /// string.
+    Base64,
+    /// URL-safe base64 (RFC 4648 §5: `-`/`_` instead of `+`/`/`), without
+    /// padding, written as a JSON string. Useful for byte payloads that may
+    /// end up embedded in a URL or filename alongside the JSON itself.
+    Base64Url,
+    /// Lowercase hexadecimal, two characters per byte, written as a JSON
+    /// string.
+    Hex,
+}
+
+/// The default recursion limit for [`SerializeOptions::max_depth`] - deep
+/// enough for any reasonably-shaped value, shallow enough to fail long
+/// before a deeply nested or cyclic-via-`Arc` value could overflow the
+/// stack.
+const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// Global fallback enum tagging strategy - see
+/// [`SerializeOptions::enum_representation`]. Only applies to enums that
+/// carry none of their own `#[facet(tag = ...)]`/`#[facet(untagged)]`
+/// attributes; an enum with such an attribute always uses it instead,
+/// regardless of this setting.
+///
+/// Note that per-type adjacent (`#[facet(tag = "type", content = "payload")]`)
+/// and internal (`#[facet(tag = "type")]`) tagging are already honored by
+/// both `to_string` and `from_str` - this enum isn't only a fallback
+/// mechanism, it's also how a single enum's own attribute gets realized on
+/// the serialize side, mirrored on the deserialize side by
+/// `JsonDeserializer::deserialize_enum_internally_tagged`/
+/// `deserialize_enum_adjacently_tagged`.
+#[derive(Debug, Clone, Copy)]
+pub enum EnumRepresentation<'a> {
+    /// `{"Variant": <content>}`, or the bare string `"Variant"` for unit
+    /// variants (unless overridden by [`SerializeOptions::enum_as_map`]).
+    /// This is the representation already used when no fallback is set.
+    External,
+    /// `{<tag>: "Variant", ...fields}`, merging the variant's own fields -
+    /// or, for a newtype variant wrapping a struct, that struct's fields -
+    /// into the same object as the tag. Only unit, struct, and
+    /// newtype-of-struct variants can be represented this way; any other
+    /// variant shape fails with [`SerializeError::UnrepresentableEnum`].
+    Internal {
+        /// The object key the variant name is written under.
+        tag: &'a str,
+    },
+    /// `{<tag>: "Variant", <content>: <content>}`, omitting `<content>`
+    /// entirely for fieldless unit variants.
+    Adjacent {
+        /// The object key the variant name is written under.
+        tag: &'a str,
+        /// The object key the variant's own content is written under.
+        content: &'a str,
+    },
+    /// The bare variant content, with no tag at all.
+    Untagged,
+}
+
+/// Options controlling JSON serialization output - see [`to_string_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct SerializeOptions<'a> {
+    indent: Option<&'a str>,
+    sort_keys: bool,
+    non_finite_floats: NonFiniteFloatHandling,
+    ensure_ascii: bool,
+    max_depth: usize,
+    enum_as_map: bool,
+    enum_representation: Option<EnumRepresentation<'a>>,
+    byte_encoding: ByteEncoding,
+    skip_none_fields: bool,
+    skip_empty_collections: bool,
+}
+
+impl<'a> Default for SerializeOptions<'a> {
+    fn default() -> Self {
+        SerializeOptions {
+            indent: None,
+            sort_keys: false,
+            non_finite_floats: NonFiniteFloatHandling::default(),
+            ensure_ascii: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+            enum_as_map: false,
+            enum_representation: None,
+            byte_encoding: ByteEncoding::default(),
+            skip_none_fields: false,
+            skip_empty_collections: false,
+        }
+    }
+}
+
+impl<'a> SerializeOptions<'a> {
+    /// Compact output, collection iteration order preserved - the same as
+    /// `Default::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pretty-print with the given indent unit (e.g. `"  "` or `"\t"`) repeated
+    /// once per nesting level.
+    pub fn indent(mut self, indent: &'a str) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Emit map entries and set elements in a stable sorted order (by
+    /// serialized key for maps, by serialized element for sets) instead of
+    /// the collection's own iteration order, which `HashMap`/`HashSet` do
+    /// not guarantee is stable across runs or processes.
+    pub fn sort_keys(mut self, enabled: bool) -> Self {
+        self.sort_keys = enabled;
+        self
+    }
+
+    /// Chooses how `NaN`/infinite floats are serialized - see
+    /// [`NonFiniteFloatHandling`]. Defaults to [`NonFiniteFloatHandling::Null`].
+    pub fn non_finite_floats(mut self, handling: NonFiniteFloatHandling) -> Self {
+        self.non_finite_floats = handling;
+        self
+    }
+
+    /// Escapes every non-ASCII scalar value as `\uXXXX` (splitting code
+    /// points above U+FFFF into a UTF-16 surrogate pair) instead of writing
+    /// raw UTF-8 bytes, matching the `ensure_ascii` option other JSON
+    /// encoders offer. Applies uniformly to scalar strings, `char`, map
+    /// keys, and enum variant/field names - useful when the output is
+    /// headed for a transport or legacy parser that isn't UTF-8 clean.
+    pub fn ensure_ascii(mut self, enabled: bool) -> Self {
+        self.ensure_ascii = enabled;
+        self
+    }
+
+    /// Bounds how deeply nested structs, maps, lists, options, and smart
+    /// pointers may recurse before serialization fails with
+    /// [`SerializeError::DepthLimitExceeded`] instead of overflowing the
+    /// stack on a deeply nested or cyclic-via-`Arc` value. Defaults to 128.
+    pub fn max_depth(mut self, limit: usize) -> Self {
+        self.max_depth = limit;
+        self
+    }
+
+    /// Forces externally-tagged enums (the default representation - see
+    /// [`ShapeAttribute`]'s tag/content/untagged attributes for the others)
+    /// to always serialize as a single-key object, wrapping unit variants as
+    /// `{"Variant": null}` instead of the bare string `"Variant"`. Borrowed
+    /// from serde_cbor's `enum_as_map`, this is for consumers that expect a
+    /// uniform `{tag: content}` shape regardless of variant payload. Has no
+    /// effect on variants serialized via a `flattened` field, which already
+    /// splice their content into the parent object with no wrapper key of
+    /// their own, nor on enums using adjacently/internally tagged or
+    /// untagged representations, which ignore this option entirely.
+    pub fn enum_as_map(mut self, enabled: bool) -> Self {
+        self.enum_as_map = enabled;
+        self
+    }
+
+    /// Sets a global fallback enum tagging strategy - see
+    /// [`EnumRepresentation`]. Only takes effect for enums with no
+    /// `#[facet(tag = ...)]`/`#[facet(untagged)]` attribute of their own;
+    /// such attributes always win over this option, which exists for
+    /// picking a uniform wire shape across types the caller doesn't
+    /// control the derive attributes of.
+    pub fn enum_representation(mut self, representation: EnumRepresentation<'a>) -> Self {
+        self.enum_representation = Some(representation);
+        self
+    }
+
+    /// Chooses how byte sequences (`Vec<u8>`, `[u8; N]`, `&[u8]`) are
+    /// written - see [`ByteEncoding`]. Defaults to [`ByteEncoding::Array`].
+    /// Applies to every byte sequence in the document; there is no
+    /// per-field override.
+    pub fn byte_encoding(mut self, encoding: ByteEncoding) -> Self {
+        self.byte_encoding = encoding;
+        self
+    }
+
+    /// Omits named struct/enum-struct-variant fields whose value is `None`
+    /// instead of writing them as `"field": null`. Has no effect on tuple
+    /// structs, tuple variants, or other positional fields, where dropping an
+    /// element would change the meaning of the ones after it.
+    pub fn skip_none_fields(mut self, enabled: bool) -> Self {
+        self.skip_none_fields = enabled;
+        self
+    }
+
+    /// Omits named struct/enum-struct-variant fields whose value is an empty
+    /// string, list, array, slice, map, or set, instead of writing them as
+    /// `"field": []`/`"field": {}`/`"field": ""`. Like
+    /// [`SerializeOptions::skip_none_fields`], this only applies to named
+    /// fields, never to positional ones.
+    pub fn skip_empty_collections(mut self, enabled: bool) -> Self {
+        self.skip_empty_collections = enabled;
+        self
+    }
+}
+
+/// Internal bundle of cross-cutting serialization settings that aren't part
+/// of the [`Formatter`] trait (they affect what is written, not how it's
+/// laid out), threaded alongside `formatter` through the recursive
+/// `serialize_*` functions.
+#[derive(Debug, Clone, Copy)]
+struct SerializeConfig<'a> {
+    sort_keys: bool,
+    non_finite_floats: NonFiniteFloatHandling,
+    ensure_ascii: bool,
+    max_depth: usize,
+    enum_as_map: bool,
+    enum_representation: Option<EnumRepresentation<'a>>,
+    byte_encoding: ByteEncoding,
+    skip_none_fields: bool,
+    skip_empty_collections: bool,
+}
+
+impl<'a> Default for SerializeConfig<'a> {
+    fn default() -> Self {
+        SerializeConfig::from_options(&SerializeOptions::default())
+    }
+}
+
+impl<'a> SerializeConfig<'a> {
+    fn from_options(options: &SerializeOptions<'a>) -> Self {
+        SerializeConfig {
+            sort_keys: options.sort_keys,
+            ensure_ascii: options.ensure_ascii,
+            non_finite_floats: options.non_finite_floats,
+            max_depth: options.max_depth,
+            enum_as_map: options.enum_as_map,
+            enum_representation: options.enum_representation,
+            byte_encoding: options.byte_encoding,
+            skip_none_fields: options.skip_none_fields,
+            skip_empty_collections: options.skip_empty_collections,
+        }
+    }
+}
+
+/// Returns `true` if a named field's value should be omitted from the
+/// output entirely, rather than written as `"field": value` - see
+/// [`SerializeOptions::skip_none_fields`] and
+/// [`SerializeOptions::skip_empty_collections`].
+fn should_skip_field(value: Peek<'_, '_>, cfg: &SerializeConfig<'_>) -> bool {
+    match value.shape().def {
+        Def::Option(_) if cfg.skip_none_fields => value.into_option().unwrap().value().is_none(),
+        Def::List(_) | Def::Array(_) | Def::Slice(_) if cfg.skip_empty_collections => {
+            value.into_list_like().unwrap().iter().next().is_none()
+        }
+        Def::Map(_) if cfg.skip_empty_collections => value.into_map().unwrap().iter().next().is_none(),
+        Def::Set(_) if cfg.skip_empty_collections => value.into_set().unwrap().iter().next().is_none(),
+        Def::Scalar if cfg.skip_empty_collections => value.as_str().is_some_and(|s| s.is_empty()),
+        _ => false,
+    }
+}
+
+/// Renders a map key's JSON string form into a byte buffer, for use as a
+/// sort key - see [`SerializeOptions::sort_keys`].
+fn map_key_sort_bytes(key: Peek<'_, '_>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    serialize_map_key(key, &mut buf, &SerializeConfig::default()).unwrap();
+    buf
+}
+
+/// Renders a set element's JSON form into a byte buffer, for use as a sort
+/// key - see [`SerializeOptions::sort_keys`]. Non-finite floats can't fail
+/// to sort-key-ify (the default `Null` handling always succeeds), so this
+/// intentionally ignores the caller's configured `non_finite_floats` choice.
+fn set_item_sort_bytes(item: Peek<'_, '_>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    serialize_value(
+        item,
+        None,
+        &mut buf,
+        &mut CompactFormatter,
+        &SerializeConfig::default(),
+        0,
+    )
+    .unwrap();
+    buf
+}
+
 /// Serializes a value implementing `Facet` to a JSON string.
 pub fn to_string<'facet, T: Facet<'facet> + ?Sized>(value: &T) -> String {
     peek_to_string(Peek::new(value))
@@ -14,6 +639,39 @@ pub fn to_string_pretty<'facet, T: Facet<'facet> + ?Sized>(value: &T) -> String
     peek_to_string_pretty(Peek::new(value))
 }
 
+/// Serializes a value to a JSON string with map entries and set elements in
+/// a deterministic, sorted order (by serialized key for maps, by serialized
+/// element for sets) instead of the collection's own iteration order, which
+/// `HashMap`/`HashSet` do not guarantee is stable across runs. A shorthand
+/// for `to_string_with(value, SerializeOptions::new().sort_keys(true))`,
+/// useful for cache keys, signatures, or golden-file test fixtures.
+pub fn to_string_sorted<'facet, T: Facet<'facet> + ?Sized>(value: &T) -> String {
+    // `sort_keys` adds no failure mode of its own and `non_finite_floats`
+    // stays at its default of `Null`, which always succeeds; the default
+    // `max_depth` limit and an internally tagged variant that can't be
+    // written as one object still return a `SerializeError`, which panics
+    // here.
+    to_string_with(value, SerializeOptions::new().sort_keys(true))
+        .expect("to_string_sorted failed to serialize the value")
+}
+
+/// Serializes a value to a JSON string with custom [`SerializeOptions`].
+///
+/// Fails with [`SerializeError::DepthLimitExceeded`] if the value nests
+/// deeper than [`SerializeOptions::max_depth`], with
+/// [`SerializeError::UnrepresentableEnum`] for an internally tagged variant
+/// that can't be written as one object, and with
+/// [`SerializeError::NonFiniteFloat`] if
+/// [`SerializeOptions::non_finite_floats`] is set to
+/// [`NonFiniteFloatHandling::Error`] and the value contains a `NaN` or
+/// infinite float.
+pub fn to_string_with<'facet, T: Facet<'facet> + ?Sized>(
+    value: &T,
+    options: SerializeOptions<'_>,
+) -> Result<String, SerializeError> {
+    peek_to_string_with(Peek::new(value), options)
+}
+
 /// Serializes a `Peek` instance to a JSON string.
 pub fn peek_to_string<'input, 'facet>(peek: Peek<'input, 'facet>) -> String {
     let mut s = Vec::new();
@@ -28,6 +686,19 @@ pub fn peek_to_string_pretty<'input, 'facet>(peek: Peek<'input, 'facet>) -> Stri
     String::from_utf8(s).unwrap()
 }
 
+/// Serializes a `Peek` instance to a JSON string with custom [`SerializeOptions`]
+/// - e.g. a configurable indent unit/width via [`SerializeOptions::indent`]
+/// (`"\t"` for tabs, `"    "` for 4-space indentation, etc.). See
+/// [`to_string_with`] for when this can fail.
+pub fn peek_to_string_with<'input, 'facet>(
+    peek: Peek<'input, 'facet>,
+    options: SerializeOptions<'_>,
+) -> Result<String, SerializeError> {
+    let mut s = Vec::new();
+    peek_to_writer_with(peek, &mut s, options)?;
+    Ok(String::from_utf8(s).unwrap())
+}
+
 /// Serializes a `Facet` value to JSON and writes it to the given writer.
 pub fn to_writer<'mem, 'facet, T: Facet<'facet>, W: crate::JsonWrite>(
     value: &'mem T,
@@ -49,7 +720,34 @@ pub fn peek_to_writer<'mem, 'facet, W: crate::JsonWrite>(
     peek: Peek<'mem, 'facet>,
     mut writer: W,
 ) -> Result<(), SerializeError> {
-    serialize_value(peek, None, &mut writer, None, 0)
+    serialize_value(
+        peek,
+        None,
+        &mut writer,
+        &mut CompactFormatter,
+        &SerializeConfig::default(),
+        0,
+    )
+}
+
+/// Serializes a `Facet` value to JSON with custom [`SerializeOptions`] and
+/// writes it to the given writer.
+pub fn to_writer_with<'mem, 'facet, T: Facet<'facet>, W: crate::JsonWrite>(
+    value: &'mem T,
+    writer: W,
+    options: SerializeOptions<'_>,
+) -> Result<(), SerializeError> {
+    peek_to_writer_with(Peek::new(value), writer, options)
+}
+
+/// Serializes a `Peek` value to JSON with custom [`SerializeOptions`] and
+/// writes it to the given writer.
+pub fn peek_to_writer_with<'mem, 'facet, W: crate::JsonWrite>(
+    peek: Peek<'mem, 'facet>,
+    mut writer: W,
+    options: SerializeOptions<'_>,
+) -> Result<(), SerializeError> {
+    serialize_with_options(peek, &mut writer, options)
 }
 
 /// Serializes a `Peek` value to pretty-printed JSON and writes it to the given writer.
@@ -57,52 +755,248 @@ pub fn peek_to_writer_pretty<'mem, 'facet, W: crate::JsonWrite>(
     peek: Peek<'mem, 'facet>,
     mut writer: W,
 ) -> Result<(), SerializeError> {
-    serialize_value(peek, None, &mut writer, Some("  "), 0)
+    serialize_value(
+        peek,
+        None,
+        &mut writer,
+        &mut PrettyFormatter::new(),
+        &SerializeConfig::default(),
+        0,
+    )
 }
 
-/// Serialization error for json, which cannot fail.
-#[derive(Debug)]
-pub enum SerializeError {}
+/// Serializes a `Facet` value to JSON using a caller-supplied [`Formatter`]
+/// and writes it to the given writer - the entry point for custom layouts
+/// (tab indentation, trailing-comma-free minified variants, aligned colons,
+/// etc.) that [`CompactFormatter`]/[`PrettyFormatter`] don't cover.
+pub fn to_writer_with_formatter<'mem, 'facet, T: Facet<'facet>, W: crate::JsonWrite, F: Formatter>(
+    value: &'mem T,
+    writer: W,
+    formatter: F,
+) -> Result<(), SerializeError> {
+    peek_to_writer_with_formatter(Peek::new(value), writer, formatter)
+}
 
-fn variant_is_newtype_like(variant: &facet_core::Variant) -> bool {
-    variant.data.kind == StructKind::Tuple && variant.data.fields.len() == 1
+/// Serializes a `Peek` value to JSON using a caller-supplied [`Formatter`]
+/// and writes it to the given writer. See [`to_writer_with_formatter`].
+pub fn peek_to_writer_with_formatter<'mem, 'facet, W: crate::JsonWrite, F: Formatter>(
+    peek: Peek<'mem, 'facet>,
+    mut writer: W,
+    mut formatter: F,
+) -> Result<(), SerializeError> {
+    serialize_value(
+        peek,
+        None,
+        &mut writer,
+        &mut formatter,
+        &SerializeConfig::default(),
+        0,
+    )
+}
+
+/// Dispatches to [`CompactFormatter`] or [`PrettyFormatter`] depending on
+/// [`SerializeOptions::indent`], since the two are distinct concrete types
+/// and `serialize_value` is generic over `F: Formatter` rather than using a
+/// trait object.
+fn serialize_with_options<'mem, 'facet, W: crate::JsonWrite>(
+    peek: Peek<'mem, 'facet>,
+    writer: &mut W,
+    options: SerializeOptions<'_>,
+) -> Result<(), SerializeError> {
+    let cfg = SerializeConfig::from_options(&options);
+    match options.indent {
+        Some(indent) => serialize_value(
+            peek,
+            None,
+            writer,
+            &mut PrettyFormatter::with_indent(indent),
+            &cfg,
+            0,
+        ),
+        None => serialize_value(peek, None, writer, &mut CompactFormatter, &cfg, 0),
+    }
+}
+
+/// Error that can occur while serializing a value to JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializeError {
+    /// A `NaN` or infinite float was encountered and
+    /// [`NonFiniteFloatHandling::Error`] was selected via
+    /// [`SerializeOptions::non_finite_floats`].
+    NonFiniteFloat,
+    /// Nesting exceeded [`SerializeOptions::max_depth`] - raised instead of
+    /// recursing further, to fail safely on deeply nested or cyclic-via-`Arc`
+    /// values rather than overflowing the stack.
+    DepthLimitExceeded,
+    /// An enum variant can't be represented under
+    /// [`EnumRepresentation::Internal`] - only unit, struct, and
+    /// newtype-of-struct variants can merge their content into the same
+    /// object as the tag; a tuple or multi-field variant has no way to be
+    /// flattened in with it.
+    UnrepresentableEnum,
+}
+
+impl core::fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SerializeError::NonFiniteFloat => {
+                f.write_str("cannot serialize a NaN or infinite float as JSON")
+            }
+            SerializeError::DepthLimitExceeded => {
+                f.write_str("exceeded the configured maximum nesting depth while serializing")
+            }
+            SerializeError::UnrepresentableEnum => f.write_str(
+                "enum variant cannot be represented under the internally tagged representation",
+            ),
+        }
+    }
 }
 
-/// Write indentation for pretty printing
-fn write_indent<W: crate::JsonWrite>(writer: &mut W, indent: Option<&str>, depth: usize) {
-    if let Some(indent_str) = indent {
-        for _ in 0..depth {
-            writer.write(indent_str.as_bytes());
+#[cfg(feature = "std")]
+impl std::error::Error for SerializeError {}
+
+/// Error returned by [`to_slice`] when the serialized JSON doesn't fit in
+/// the supplied buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferFull;
+
+impl core::fmt::Display for BufferFull {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("buffer too small to hold the serialized JSON")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferFull {}
+
+/// A [`crate::JsonWrite`] over a fixed, caller-supplied byte slice, used by
+/// [`to_slice`] to serialize without allocating. `JsonWrite::write` has no
+/// way to fail, so bytes that would overrun `buf` are dropped and recorded
+/// via `overflowed` instead, and `to_slice` turns that into a `BufferFull`
+/// error once serialization finishes.
+struct SliceWriter<'buf> {
+    buf: &'buf mut [u8],
+    len: usize,
+    overflowed: bool,
+}
+
+impl<'buf> SliceWriter<'buf> {
+    fn new(buf: &'buf mut [u8]) -> Self {
+        SliceWriter {
+            buf,
+            len: 0,
+            overflowed: false,
         }
     }
 }
 
-/// Write a newline for pretty printing
-fn write_newline<W: crate::JsonWrite>(writer: &mut W, indent: Option<&str>) {
-    if indent.is_some() {
-        writer.write(b"\n");
+impl<'buf> crate::JsonWrite for SliceWriter<'buf> {
+    fn write(&mut self, bytes: &[u8]) {
+        let remaining = self.buf.len() - self.len;
+        if bytes.len() > remaining {
+            self.overflowed = true;
+            return;
+        }
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
     }
 }
 
-/// Write a space after colon for pretty printing
-fn write_colon<W: crate::JsonWrite>(writer: &mut W, indent: Option<&str>) {
-    if indent.is_some() {
-        writer.write(b": ");
+/// Serializes a value implementing `Facet` to JSON, writing into `buf`
+/// without allocating - suitable for `no_std`/embedded/Wasm use where
+/// `to_string`'s heap allocation isn't available. Returns the number of
+/// bytes written, or [`BufferFull`] if `buf` was too small to hold the
+/// output (in which case `buf`'s contents are unspecified and should be
+/// discarded).
+pub fn to_slice<'facet, T: Facet<'facet> + ?Sized>(
+    value: &T,
+    buf: &mut [u8],
+) -> Result<usize, BufferFull> {
+    let mut writer = SliceWriter::new(buf);
+    serialize_value(
+        Peek::new(value),
+        None,
+        &mut writer,
+        &mut CompactFormatter,
+        &SerializeConfig::default(),
+        0,
+    )
+    .unwrap();
+    if writer.overflowed {
+        Err(BufferFull)
     } else {
-        writer.write(b":");
+        Ok(writer.len)
+    }
+}
+
+/// Error from [`to_slice_with_options`] - either the buffer was too small
+/// ([`BufferFull`]), or serialization itself failed (e.g. a non-finite float
+/// under [`NonFiniteFloatHandling::Error`], or a nesting depth over
+/// [`SerializeOptions::max_depth`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToSliceError {
+    /// See [`BufferFull`].
+    BufferFull,
+    /// See [`SerializeError`].
+    Serialize(SerializeError),
+}
+
+impl core::fmt::Display for ToSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ToSliceError::BufferFull => BufferFull.fmt(f),
+            ToSliceError::Serialize(e) => e.fmt(f),
+        }
     }
 }
 
-fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
+#[cfg(feature = "std")]
+impl std::error::Error for ToSliceError {}
+
+/// Like [`to_slice`], but with custom [`SerializeOptions`] - e.g.
+/// [`SerializeOptions::sort_keys`] for deterministic map/set ordering
+/// together with [`SerializeOptions::non_finite_floats`] set to
+/// [`NonFiniteFloatHandling::Error`], for output that is both
+/// allocation-free and reproducible byte-for-byte, which embedded/Wasm
+/// targets often need from their serialized output.
+pub fn to_slice_with_options<'facet, T: Facet<'facet> + ?Sized>(
+    value: &T,
+    buf: &mut [u8],
+    options: SerializeOptions<'_>,
+) -> Result<usize, ToSliceError> {
+    let mut writer = SliceWriter::new(buf);
+    serialize_with_options(Peek::new(value), &mut writer, options)
+        .map_err(ToSliceError::Serialize)?;
+    if writer.overflowed {
+        Err(ToSliceError::BufferFull)
+    } else {
+        Ok(writer.len)
+    }
+}
+
+fn variant_is_newtype_like(variant: &facet_core::Variant) -> bool {
+    variant.data.kind == StructKind::Tuple && variant.data.fields.len() == 1
+}
+
+fn serialize_value<'mem, 'facet, W: crate::JsonWrite, F: Formatter>(
     mut peek: Peek<'mem, 'facet>,
     maybe_field: Option<Field>,
     writer: &mut W,
-    indent: Option<&str>,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
     depth: usize,
 ) -> Result<(), SerializeError> {
+    if depth > cfg.max_depth {
+        return Err(SerializeError::DepthLimitExceeded);
+    }
+
     trace!("Serializing a value, shape is {}", peek.shape());
 
-    // Handle custom serialization
+    // Handle custom serialization via a `serialize_with` vtable entry. This
+    // is the mechanism a caller reaches for today to give one particular
+    // field its own wire encoding (e.g. base64 for a single `Vec<u8>`
+    // field) - there's no dedicated field-level attribute for that, only
+    // the crate-wide `SerializeOptions::byte_encoding`.
     #[cfg(feature = "alloc")]
     if let Some(f) = maybe_field {
         if f.vtable.serialize_with.is_some() {
@@ -110,10 +1004,43 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
             let old_shape = peek.shape();
             let new_shape = owned_peek.shape();
             trace!("{old_shape} has custom serialization, serializing as {new_shape} instead");
-            return serialize_value(owned_peek.as_peek(), None, writer, indent, depth);
+            return serialize_value(owned_peek.as_peek(), None, writer, formatter, cfg, depth);
         }
     }
 
+    // The dynamic JsonValue type serializes as the JSON value it represents,
+    // not as a regular externally-tagged enum.
+    if peek.shape().type_identifier == "JsonValue" {
+        return serialize_json_value(peek, writer, formatter, cfg, depth);
+    }
+
+    // Same as JsonValue - the spans it additionally carries have no wire
+    // representation, so it serializes identically.
+    if peek.shape().type_identifier == "SpannedJsonValue" {
+        return serialize_spanned_json_value(peek, writer, formatter, cfg, depth);
+    }
+
+    // The raw-JSON passthrough type writes its captured source text back out
+    // verbatim instead of going through the normal struct reflection path.
+    if peek.shape().type_identifier == "JsonRaw" {
+        let raw = peek
+            .get::<crate::raw::JsonRaw<'_>>()
+            .expect("JsonRaw shape mismatch");
+        writer.write(raw.get().as_bytes());
+        return Ok(());
+    }
+
+    // The arbitrary-precision number type writes its captured digits back
+    // out verbatim, rather than round-tripping through a native int/float
+    // that might not be able to represent them exactly.
+    if peek.shape().type_identifier == "JsonNumber" {
+        let number = peek
+            .get::<crate::number::JsonNumber<'_>>()
+            .expect("JsonNumber shape mismatch");
+        writer.write(number.as_str().as_bytes());
+        return Ok(());
+    }
+
     // Handle transparent types
     if peek
         .shape()
@@ -137,16 +1064,16 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
     match (peek.shape().def, peek.shape().ty) {
         (Def::Scalar, _) => {
             let peek = peek.innermost_peek();
-            serialize_scalar(peek, writer)?;
+            serialize_scalar(peek, writer, formatter, cfg)?;
         }
         (Def::List(ld), _) => {
             if ld.t().is_type::<u8>() && peek.shape().is_type::<Vec<u8>>() {
-                // Special case for Vec<u8> - serialize as array of numbers
+                // Special case for Vec<u8> - honors cfg.byte_encoding
                 let bytes = peek.get::<Vec<u8>>().unwrap();
-                serialize_byte_array(bytes, writer, indent, depth)?;
+                serialize_byte_array(bytes, writer, formatter, cfg)?;
             } else {
                 let peek_list = peek.into_list_like().unwrap();
-                serialize_array(peek_list.iter(), writer, indent, depth)?;
+                serialize_array(peek_list.iter(), writer, formatter, cfg, depth)?;
             }
         }
         (Def::Array(ad), _) => {
@@ -157,73 +1084,68 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
                     .iter()
                     .map(|p| *p.get::<u8>().unwrap())
                     .collect();
-                serialize_byte_array(&bytes, writer, indent, depth)?;
+                serialize_byte_array(&bytes, writer, formatter, cfg)?;
             } else {
                 let peek_list = peek.into_list_like().unwrap();
-                serialize_array(peek_list.iter(), writer, indent, depth)?;
+                serialize_array(peek_list.iter(), writer, formatter, cfg, depth)?;
             }
         }
         (Def::Slice(sd), _) => {
             if sd.t().is_type::<u8>() {
                 let bytes = peek.get::<[u8]>().unwrap();
-                serialize_byte_array(bytes, writer, indent, depth)?;
+                serialize_byte_array(bytes, writer, formatter, cfg)?;
             } else {
                 let peek_list = peek.into_list_like().unwrap();
-                serialize_array(peek_list.iter(), writer, indent, depth)?;
+                serialize_array(peek_list.iter(), writer, formatter, cfg, depth)?;
             }
         }
         (Def::Map(_), _) => {
             let peek_map = peek.into_map().unwrap();
-            writer.write(b"{");
+            let mut entries: Vec<_> = peek_map.iter().collect();
+            if cfg.sort_keys {
+                entries.sort_by(|(a, _), (b, _)| map_key_sort_bytes(*a).cmp(&map_key_sort_bytes(*b)));
+            }
+            formatter.begin_object(writer);
             let mut first = true;
-            for (key, value) in peek_map.iter() {
-                if !first {
-                    writer.write(b",");
-                }
+            for (key, value) in entries {
+                formatter.begin_object_key(writer, first);
                 first = false;
-                write_newline(writer, indent);
-                write_indent(writer, indent, depth + 1);
-                serialize_map_key(key, writer)?;
-                write_colon(writer, indent);
-                serialize_value(value, None, writer, indent, depth + 1)?;
-            }
-            if !first {
-                write_newline(writer, indent);
-                write_indent(writer, indent, depth);
+                serialize_map_key(key, writer, cfg)?;
+                formatter.end_object_key(writer);
+                formatter.begin_object_value(writer);
+                serialize_value(value, None, writer, formatter, cfg, depth + 1)?;
+                formatter.end_object_value(writer);
             }
-            writer.write(b"}");
+            formatter.end_object(writer);
         }
         (Def::Set(_), _) => {
             let peek_set = peek.into_set().unwrap();
-            writer.write(b"[");
+            let mut items: Vec<_> = peek_set.iter().collect();
+            if cfg.sort_keys {
+                items.sort_by(|a, b| set_item_sort_bytes(*a).cmp(&set_item_sort_bytes(*b)));
+            }
+            formatter.begin_array(writer);
             let mut first = true;
-            for item in peek_set.iter() {
-                if !first {
-                    writer.write(b",");
-                }
+            for item in items {
+                formatter.array_value_separator(writer, first);
                 first = false;
-                write_newline(writer, indent);
-                write_indent(writer, indent, depth + 1);
-                serialize_value(item, None, writer, indent, depth + 1)?;
+                serialize_value(item, None, writer, formatter, cfg, depth + 1)?;
+                formatter.end_array_value(writer);
             }
-            if !first {
-                write_newline(writer, indent);
-                write_indent(writer, indent, depth);
-            }
-            writer.write(b"]");
+            formatter.end_array(writer);
         }
         (Def::Option(_), _) => {
             let opt = peek.into_option().unwrap();
             if let Some(inner_peek) = opt.value() {
-                serialize_value(inner_peek, None, writer, indent, depth)?;
+                serialize_value(inner_peek, None, writer, formatter, cfg, depth + 1)?;
             } else {
-                writer.write(b"null");
+                formatter.write_null(writer);
             }
         }
         (Def::Pointer(_), _) => {
             let sp = peek.into_pointer().unwrap();
             if let Some(inner_peek) = sp.borrow_inner() {
-                serialize_value(inner_peek, None, writer, indent, depth)?;
+                serialize_value(inner_peek, None, writer, formatter, cfg, depth + 1)?;
             } else {
                 panic!(
                     "Smart pointer without borrow support or with opaque pointee cannot be serialized"
@@ -231,6 +1153,7 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
             }
         }
         (_, Type::User(UserType::Struct(sd))) => {
+            let struct_shape = peek.shape();
             trace!("Serializing struct: shape={}", peek.shape());
             trace!(
                 "  Struct details: kind={:?}, field_count={}",
@@ -240,66 +1163,84 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
 
             match sd.kind {
                 StructKind::Unit => {
-                    writer.write(b"null");
+                    formatter.write_null(writer);
                 }
                 StructKind::Tuple => {
                     let peek_struct = peek.into_struct().unwrap();
-                    writer.write(b"[");
+                    formatter.begin_array(writer);
                     let mut first = true;
                     for (field, value) in peek_struct.fields() {
-                        if !first {
-                            writer.write(b",");
-                        }
+                        formatter.array_value_separator(writer, first);
                         first = false;
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth + 1);
-                        serialize_value(value, Some(field), writer, indent, depth + 1)?;
-                    }
-                    if !first {
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth);
+                        serialize_value(value, Some(field), writer, formatter, cfg, depth + 1)?;
+                        formatter.end_array_value(writer);
                     }
-                    writer.write(b"]");
+                    formatter.end_array(writer);
                 }
                 StructKind::TupleStruct => {
                     let peek_struct = peek.into_struct().unwrap();
-                    writer.write(b"[");
+                    formatter.begin_array(writer);
                     let mut first = true;
                     for (field, value) in peek_struct.fields_for_serialize() {
-                        if !first {
-                            writer.write(b",");
-                        }
+                        formatter.array_value_separator(writer, first);
                         first = false;
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth + 1);
-                        serialize_value(value, Some(field), writer, indent, depth + 1)?;
-                    }
-                    if !first {
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth);
+                        serialize_value(value, Some(field), writer, formatter, cfg, depth + 1)?;
+                        formatter.end_array_value(writer);
                     }
-                    writer.write(b"]");
+                    formatter.end_array(writer);
                 }
                 StructKind::Struct => {
                     let peek_struct = peek.into_struct().unwrap();
-                    writer.write(b"{");
+                    let skip_field: Vec<bool> = if cfg.skip_none_fields || cfg.skip_empty_collections {
+                        peek_struct
+                            .fields_for_serialize()
+                            .map(|(_, value)| should_skip_field(value, cfg))
+                            .collect()
+                    } else {
+                        Vec::new()
+                    };
+                    formatter.begin_object(writer);
                     let mut first = true;
-                    for (field, value) in peek_struct.fields_for_serialize() {
-                        if !first {
-                            writer.write(b",");
+                    for (i, (field, value)) in peek_struct.fields_for_serialize().enumerate() {
+                        if skip_field.get(i).copied().unwrap_or(false) {
+                            continue;
+                        }
+                        if field.flattened && matches!(field.shape().def, Def::Map(_)) {
+                            // Catch-all flatten map: splice its entries in at
+                            // the top level instead of nesting them under the
+                            // field's own name.
+                            let peek_map = value.into_map().unwrap();
+                            let mut entries: Vec<_> = peek_map.iter().collect();
+                            if cfg.sort_keys {
+                                entries.sort_by(|(a, _), (b, _)| {
+                                    map_key_sort_bytes(*a).cmp(&map_key_sort_bytes(*b))
+                                });
+                            }
+                            for (key, map_value) in entries {
+                                formatter.begin_object_key(writer, first);
+                                first = false;
+                                serialize_map_key(key, writer, cfg)?;
+                                formatter.end_object_key(writer);
+                                formatter.begin_object_value(writer);
+                                serialize_value(map_value, None, writer, formatter, cfg, depth + 1)?;
+                                formatter.end_object_value(writer);
+                            }
+                            continue;
                         }
+                        formatter.begin_object_key(writer, first);
                         first = false;
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth + 1);
-                        crate::write_json_string(writer, field.name);
-                        write_colon(writer, indent);
-                        serialize_value(value, Some(field), writer, indent, depth + 1)?;
+                        write_string(
+                            writer,
+                            formatter,
+                            cfg,
+                            render_name(struct_shape, field.name).as_ref(),
+                        );
+                        formatter.end_object_key(writer);
+                        formatter.begin_object_value(writer);
+                        serialize_value(value, Some(field), writer, formatter, cfg, depth + 1)?;
+                        formatter.end_object_value(writer);
                     }
-                    if !first {
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth);
-                    }
-                    writer.write(b"}");
+                    formatter.end_object(writer);
                 }
             }
         }
@@ -314,101 +1255,167 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
                 .expect("Failed to get variant index");
             trace!("Active variant index is {variant_index}, variant is {variant:?}");
 
-            // Determine enum tagging strategy
-            let is_untagged = shape.is_untagged();
-            let tag_field = shape.get_tag_attr();
-            let content_field = shape.get_content_attr();
+            // Determine enum tagging strategy: the enum's own attributes
+            // always win; `cfg.enum_representation` is only consulted for
+            // an enum that carries none of its own.
+            let representation = if shape.is_untagged() {
+                EnumRepresentation::Untagged
+            } else if let Some(tag) = shape.get_tag_attr() {
+                match shape.get_content_attr() {
+                    Some(content) => EnumRepresentation::Adjacent { tag, content },
+                    None => EnumRepresentation::Internal { tag },
+                }
+            } else {
+                cfg.enum_representation
+                    .unwrap_or(EnumRepresentation::External)
+            };
 
-            if is_untagged {
-                // Untagged: serialize content directly without any tag
-                serialize_enum_content(&peek_enum, variant, writer, indent, depth)?;
-            } else if let Some(tag) = tag_field {
-                if let Some(content) = content_field {
+            match representation {
+                EnumRepresentation::Untagged => {
+                    // Untagged: serialize content directly without any tag
+                    serialize_enum_content(shape, &peek_enum, variant, writer, formatter, cfg, depth)?;
+                }
+                EnumRepresentation::Adjacent { tag, content } => {
                     // Adjacently tagged: {"tag": "Variant", "content": ...}
-                    writer.write(b"{");
-                    write_newline(writer, indent);
-                    write_indent(writer, indent, depth + 1);
-                    crate::write_json_string(writer, tag);
-                    write_colon(writer, indent);
-                    crate::write_json_string(writer, variant.name);
+                    formatter.begin_object(writer);
+                    formatter.begin_object_key(writer, true);
+                    write_string(writer, formatter, cfg, tag);
+                    formatter.end_object_key(writer);
+                    formatter.begin_object_value(writer);
+                    write_string(writer, formatter, cfg, variant.name);
+                    formatter.end_object_value(writer);
 
                     // Only include content field if variant has data
                     if !variant.data.fields.is_empty() {
-                        writer.write(b",");
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth + 1);
-                        crate::write_json_string(writer, content);
-                        write_colon(writer, indent);
-                        serialize_enum_content(&peek_enum, variant, writer, indent, depth + 1)?;
+                        formatter.begin_object_key(writer, false);
+                        write_string(writer, formatter, cfg, content);
+                        formatter.end_object_key(writer);
+                        formatter.begin_object_value(writer);
+                        serialize_enum_content(
+                            shape, &peek_enum, variant, writer, formatter, cfg, depth,
+                        )?;
+                        formatter.end_object_value(writer);
                     }
 
-                    write_newline(writer, indent);
-                    write_indent(writer, indent, depth);
-                    writer.write(b"}");
-                } else {
-                    // Internally tagged: {"tag": "Variant", ...fields...}
-                    writer.write(b"{");
-                    write_newline(writer, indent);
-                    write_indent(writer, indent, depth + 1);
-                    crate::write_json_string(writer, tag);
-                    write_colon(writer, indent);
-                    crate::write_json_string(writer, variant.name);
-
-                    // Add struct fields at same level as tag
-                    for (field, field_peek) in peek_enum.fields_for_serialize() {
-                        writer.write(b",");
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth + 1);
-                        crate::write_json_string(writer, field.name);
-                        write_colon(writer, indent);
-                        serialize_value(field_peek, Some(field), writer, indent, depth + 1)?;
+                    formatter.end_object(writer);
+                }
+                EnumRepresentation::Internal { tag } => {
+                    // Internally tagged: {"tag": "Variant", ...fields...} -
+                    // only unit, struct, and newtype-of-struct variants can
+                    // merge their content into the same object as the tag.
+                    formatter.begin_object(writer);
+                    formatter.begin_object_key(writer, true);
+                    write_string(writer, formatter, cfg, tag);
+                    formatter.end_object_key(writer);
+                    formatter.begin_object_value(writer);
+                    write_string(writer, formatter, cfg, variant.name);
+                    formatter.end_object_value(writer);
+
+                    match variant.data.kind {
+                        StructKind::Unit => {}
+                        StructKind::Struct => {
+                            // Add struct fields at the same level as the tag
+                            let skip_field: Vec<bool> =
+                                if cfg.skip_none_fields || cfg.skip_empty_collections {
+                                    peek_enum
+                                        .fields_for_serialize()
+                                        .map(|(_, value)| should_skip_field(value, cfg))
+                                        .collect()
+                                } else {
+                                    Vec::new()
+                                };
+                            for (i, (field, field_peek)) in
+                                peek_enum.fields_for_serialize().enumerate()
+                            {
+                                if skip_field.get(i).copied().unwrap_or(false) {
+                                    continue;
+                                }
+                                formatter.begin_object_key(writer, false);
+                                write_string(writer, formatter, cfg, field.name);
+                                formatter.end_object_key(writer);
+                                formatter.begin_object_value(writer);
+                                serialize_value(field_peek, Some(field), writer, formatter, cfg, depth + 1)?;
+                                formatter.end_object_value(writer);
+                            }
+                        }
+                        _ if variant_is_newtype_like(variant) => {
+                            // Newtype-of-struct: flatten the wrapped
+                            // struct's own fields in at the tag's level.
+                            let fields: Vec<_> = peek_enum.fields_for_serialize().collect();
+                            let (_, inner_peek) = fields[0];
+                            if !matches!(inner_peek.shape().ty, Type::User(UserType::Struct(_))) {
+                                return Err(SerializeError::UnrepresentableEnum);
+                            }
+                            let inner_struct = inner_peek.into_struct().unwrap();
+                            let skip_field: Vec<bool> =
+                                if cfg.skip_none_fields || cfg.skip_empty_collections {
+                                    inner_struct
+                                        .fields_for_serialize()
+                                        .map(|(_, value)| should_skip_field(value, cfg))
+                                        .collect()
+                                } else {
+                                    Vec::new()
+                                };
+                            for (i, (field, field_peek)) in
+                                inner_struct.fields_for_serialize().enumerate()
+                            {
+                                if skip_field.get(i).copied().unwrap_or(false) {
+                                    continue;
+                                }
+                                formatter.begin_object_key(writer, false);
+                                write_string(writer, formatter, cfg, field.name);
+                                formatter.end_object_key(writer);
+                                formatter.begin_object_value(writer);
+                                serialize_value(field_peek, Some(field), writer, formatter, cfg, depth + 1)?;
+                                formatter.end_object_value(writer);
+                            }
+                        }
+                        _ => return Err(SerializeError::UnrepresentableEnum),
                     }
 
-                    write_newline(writer, indent);
-                    write_indent(writer, indent, depth);
-                    writer.write(b"}");
+                    formatter.end_object(writer);
                 }
-            } else {
-                // Externally tagged (default): {"Variant": content} or "Variant" for unit
-                let flattened = maybe_field.map(|f| f.flattened).unwrap_or_default();
+                EnumRepresentation::External => {
+                    // Externally tagged (default): {"Variant": content} or "Variant" for unit,
+                    // unless `enum_as_map` forces unit variants into `{"Variant": null}` too.
+                    let flattened = maybe_field.map(|f| f.flattened).unwrap_or_default();
 
-                if variant.data.fields.is_empty() {
-                    // Unit variant - just the name as a string
-                    crate::write_json_string(writer, variant.name);
-                } else {
-                    if !flattened {
-                        // Wrap in object with variant name as key
-                        writer.write(b"{");
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth + 1);
-                        crate::write_json_string(writer, variant.name);
-                        write_colon(writer, indent);
-                    }
+                    if variant.data.fields.is_empty() && !cfg.enum_as_map {
+                        // Unit variant - just the name as a string
+                        write_string(writer, formatter, cfg, render_name(shape, variant.name).as_ref());
+                    } else {
+                        if !flattened {
+                            // Wrap in object with variant name as key
+                            formatter.begin_object(writer);
+                            formatter.begin_object_key(writer, true);
+                            write_string(writer, formatter, cfg, render_name(shape, variant.name).as_ref());
+                            formatter.end_object_key(writer);
+                            formatter.begin_object_value(writer);
+                        }
 
-                    let inner_depth = if flattened { depth } else { depth + 1 };
-                    serialize_enum_content(&peek_enum, variant, writer, indent, inner_depth)?;
+                        serialize_enum_content(shape, &peek_enum, variant, writer, formatter, cfg, depth)?;
 
-                    if !flattened {
-                        write_newline(writer, indent);
-                        write_indent(writer, indent, depth);
-                        writer.write(b"}");
+                        if !flattened {
+                            formatter.end_object_value(writer);
+                            formatter.end_object(writer);
+                        }
                     }
                 }
             }
         }
         (_, Type::Pointer(pointer_type)) => {
             if let Some(str_value) = peek.as_str() {
-                crate::write_json_string(writer, str_value);
+                write_string(writer, formatter, cfg, str_value);
             } else if let Some(bytes) = peek.as_bytes() {
-                serialize_byte_array(bytes, writer, indent, depth)?;
+                serialize_byte_array(bytes, writer, formatter, cfg)?;
             } else if let PointerType::Function(_) = pointer_type {
-                writer.write(b"null");
+                formatter.write_null(writer);
             } else {
                 let innermost = peek.innermost_peek();
                 if innermost.shape() != peek.shape() {
-                    serialize_value(innermost, None, writer, indent, depth)?;
+                    serialize_value(innermost, None, writer, formatter, cfg, depth + 1)?;
                 } else {
-                    writer.write(b"null");
+                    formatter.write_null(writer);
                 }
             }
         }
@@ -417,10 +1424,144 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
                 "Unhandled type: {:?}, falling back to null",
                 peek.shape().ty
             );
-            writer.write(b"null");
+            formatter.write_null(writer);
+        }
+    }
+
+    Ok(())
+}
+
+/// Serialize the dynamic [`crate::value::JsonValue`] type as the JSON value
+/// it represents, rather than as a regular externally-tagged enum.
+fn serialize_json_value<'mem, 'facet, W: crate::JsonWrite, F: Formatter>(
+    peek: Peek<'mem, 'facet>,
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+    depth: usize,
+) -> Result<(), SerializeError> {
+    let value = peek
+        .get::<crate::value::JsonValue<'_>>()
+        .expect("JsonValue shape mismatch");
+    write_json_value(value, writer, formatter, cfg, depth)
+}
+
+fn write_json_value<'a, W: crate::JsonWrite, F: Formatter>(
+    value: &crate::value::JsonValue<'a>,
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+    depth: usize,
+) -> Result<(), SerializeError> {
+    use crate::value::JsonValue;
+
+    if depth > cfg.max_depth {
+        return Err(SerializeError::DepthLimitExceeded);
+    }
+
+    match value {
+        JsonValue::Null => formatter.write_null(writer),
+        JsonValue::Bool(b) => formatter.write_bool(writer, *b),
+        JsonValue::I64(n) => formatter.write_i64(writer, *n),
+        JsonValue::U64(n) => formatter.write_u64(writer, *n),
+        JsonValue::I128(n) => formatter.write_i128(writer, *n),
+        JsonValue::U128(n) => formatter.write_u128(writer, *n),
+        JsonValue::F64(n) => formatter.write_f64(writer, *n),
+        JsonValue::String(s) => write_string(writer, formatter, cfg, s),
+        JsonValue::Array(items) => {
+            formatter.begin_array(writer);
+            let mut first = true;
+            for item in items {
+                formatter.array_value_separator(writer, first);
+                first = false;
+                write_json_value(item, writer, formatter, cfg, depth + 1)?;
+                formatter.end_array_value(writer);
+            }
+            formatter.end_array(writer);
+        }
+        JsonValue::Object(members) => {
+            formatter.begin_object(writer);
+            let mut first = true;
+            for (key, value) in members {
+                formatter.begin_object_key(writer, first);
+                first = false;
+                write_string(writer, formatter, cfg, key);
+                formatter.end_object_key(writer);
+                formatter.begin_object_value(writer);
+                write_json_value(value, writer, formatter, cfg, depth + 1)?;
+                formatter.end_object_value(writer);
+            }
+            formatter.end_object(writer);
         }
     }
+    Ok(())
+}
+
+/// Serialize the dynamic [`crate::value::SpannedJsonValue`] type as the JSON
+/// value it represents - the spans themselves carry no wire representation,
+/// so this writes exactly what [`serialize_json_value`] would for the
+/// equivalent unspanned [`crate::value::JsonValue`].
+fn serialize_spanned_json_value<'mem, 'facet, W: crate::JsonWrite, F: Formatter>(
+    peek: Peek<'mem, 'facet>,
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+    depth: usize,
+) -> Result<(), SerializeError> {
+    let value = peek
+        .get::<crate::value::SpannedJsonValue<'_>>()
+        .expect("SpannedJsonValue shape mismatch");
+    write_spanned_json_value(value, writer, formatter, cfg, depth)
+}
 
+fn write_spanned_json_value<'a, W: crate::JsonWrite, F: Formatter>(
+    value: &crate::value::SpannedJsonValue<'a>,
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+    depth: usize,
+) -> Result<(), SerializeError> {
+    use crate::value::SpannedJsonValue;
+
+    if depth > cfg.max_depth {
+        return Err(SerializeError::DepthLimitExceeded);
+    }
+
+    match value {
+        SpannedJsonValue::Null => formatter.write_null(writer),
+        SpannedJsonValue::Bool(b) => formatter.write_bool(writer, *b),
+        SpannedJsonValue::I64(n) => formatter.write_i64(writer, *n),
+        SpannedJsonValue::U64(n) => formatter.write_u64(writer, *n),
+        SpannedJsonValue::I128(n) => formatter.write_i128(writer, *n),
+        SpannedJsonValue::U128(n) => formatter.write_u128(writer, *n),
+        SpannedJsonValue::F64(n) => formatter.write_f64(writer, *n),
+        SpannedJsonValue::String(s) => write_string(writer, formatter, cfg, s),
+        SpannedJsonValue::Array(items) => {
+            formatter.begin_array(writer);
+            let mut first = true;
+            for item in items {
+                formatter.array_value_separator(writer, first);
+                first = false;
+                write_spanned_json_value(&item.node, writer, formatter, cfg, depth + 1)?;
+                formatter.end_array_value(writer);
+            }
+            formatter.end_array(writer);
+        }
+        SpannedJsonValue::Object(members) => {
+            formatter.begin_object(writer);
+            let mut first = true;
+            for (key, value) in members {
+                formatter.begin_object_key(writer, first);
+                first = false;
+                write_string(writer, formatter, cfg, key);
+                formatter.end_object_key(writer);
+                formatter.begin_object_value(writer);
+                write_spanned_json_value(&value.node, writer, formatter, cfg, depth + 1)?;
+                formatter.end_object_value(writer);
+            }
+            formatter.end_object(writer);
+        }
+    }
     Ok(())
 }
 
@@ -428,10 +1569,15 @@ fn serialize_value<'mem, 'facet, W: crate::JsonWrite>(
 fn serialize_map_key<W: crate::JsonWrite>(
     peek: Peek<'_, '_>,
     writer: &mut W,
+    cfg: &SerializeConfig<'_>,
 ) -> Result<(), SerializeError> {
     // First try as_str() which handles &str, String, Cow<str>, etc uniformly
     if let Some(s) = peek.as_str() {
-        crate::write_json_string(writer, s);
+        if cfg.ensure_ascii {
+            write_json_string_ascii(writer, s);
+        } else {
+            crate::write_json_string(writer, s);
+        }
         return Ok(());
     }
 
@@ -522,89 +1668,80 @@ fn serialize_map_key<W: crate::JsonWrite>(
     Ok(())
 }
 
-fn serialize_scalar<W: crate::JsonWrite>(
+fn serialize_scalar<W: crate::JsonWrite, F: Formatter>(
     peek: Peek<'_, '_>,
     writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
 ) -> Result<(), SerializeError> {
     match peek.scalar_type() {
-        Some(ScalarType::Unit) => writer.write(b"null"),
-        Some(ScalarType::Bool) => {
-            let v = *peek.get::<bool>().unwrap();
-            writer.write(if v { b"true" } else { b"false" });
-        }
+        Some(ScalarType::Unit) => formatter.write_null(writer),
+        Some(ScalarType::Bool) => formatter.write_bool(writer, *peek.get::<bool>().unwrap()),
         Some(ScalarType::Char) => {
             let c = *peek.get::<char>().unwrap();
             writer.write(b"\"");
-            crate::write_json_escaped_char(writer, c);
+            if cfg.ensure_ascii {
+                write_ascii_escaped_char(writer, c);
+            } else {
+                crate::write_json_escaped_char(writer, c);
+            }
             writer.write(b"\"");
         }
         Some(ScalarType::Str) => {
-            crate::write_json_string(writer, peek.get::<str>().unwrap());
+            write_string(writer, formatter, cfg, peek.get::<str>().unwrap());
         }
         Some(ScalarType::String) => {
-            crate::write_json_string(writer, peek.get::<String>().unwrap());
+            write_string(writer, formatter, cfg, peek.get::<String>().unwrap());
         }
         Some(ScalarType::CowStr) => {
-            crate::write_json_string(
+            write_string(
                 writer,
+                formatter,
+                cfg,
                 peek.get::<alloc::borrow::Cow<'_, str>>().unwrap().as_ref(),
             );
         }
         Some(ScalarType::F32) => {
-            let v = *peek.get::<f32>().unwrap();
-            writer.write(ryu::Buffer::new().format(v).as_bytes());
+            write_f32(*peek.get::<f32>().unwrap(), writer, formatter, cfg)?;
         }
         Some(ScalarType::F64) => {
-            let v = *peek.get::<f64>().unwrap();
-            writer.write(ryu::Buffer::new().format(v).as_bytes());
+            write_f64(*peek.get::<f64>().unwrap(), writer, formatter, cfg)?;
         }
         Some(ScalarType::U8) => {
-            let v = *peek.get::<u8>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_u8(writer, *peek.get::<u8>().unwrap());
         }
         Some(ScalarType::U16) => {
-            let v = *peek.get::<u16>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_u16(writer, *peek.get::<u16>().unwrap());
         }
         Some(ScalarType::U32) => {
-            let v = *peek.get::<u32>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_u32(writer, *peek.get::<u32>().unwrap());
         }
         Some(ScalarType::U64) => {
-            let v = *peek.get::<u64>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_u64(writer, *peek.get::<u64>().unwrap());
         }
         Some(ScalarType::U128) => {
-            let v = *peek.get::<u128>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_u128(writer, *peek.get::<u128>().unwrap());
         }
         Some(ScalarType::USize) => {
-            let v = *peek.get::<usize>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_usize(writer, *peek.get::<usize>().unwrap());
         }
         Some(ScalarType::I8) => {
-            let v = *peek.get::<i8>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_i8(writer, *peek.get::<i8>().unwrap());
         }
         Some(ScalarType::I16) => {
-            let v = *peek.get::<i16>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_i16(writer, *peek.get::<i16>().unwrap());
         }
         Some(ScalarType::I32) => {
-            let v = *peek.get::<i32>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_i32(writer, *peek.get::<i32>().unwrap());
         }
         Some(ScalarType::I64) => {
-            let v = *peek.get::<i64>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_i64(writer, *peek.get::<i64>().unwrap());
         }
         Some(ScalarType::I128) => {
-            let v = *peek.get::<i128>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_i128(writer, *peek.get::<i128>().unwrap());
         }
         Some(ScalarType::ISize) => {
-            let v = *peek.get::<isize>().unwrap();
-            writer.write(itoa::Buffer::new().format(v).as_bytes());
+            formatter.write_isize(writer, *peek.get::<isize>().unwrap());
         }
         Some(unsupported) => {
             panic!("Unsupported scalar type: {unsupported:?}")
@@ -612,7 +1749,7 @@ fn serialize_scalar<W: crate::JsonWrite>(
         None => {
             // Try Display formatting if available
             if peek.shape().vtable.display.is_some() {
-                crate::write_json_string(writer, &alloc::format!("{peek}"));
+                write_string(writer, formatter, cfg, &alloc::format!("{peek}"));
             } else {
                 panic!("Unsupported shape (no display): {}", peek.shape())
             }
@@ -621,111 +1758,416 @@ fn serialize_scalar<W: crate::JsonWrite>(
     Ok(())
 }
 
-fn serialize_array<'mem, 'facet, W: crate::JsonWrite>(
+/// Writes an `f32`, applying [`SerializeConfig::non_finite_floats`] if the
+/// value is `NaN` or infinite - see [`NonFiniteFloatHandling`].
+fn write_f32<W: crate::JsonWrite, F: Formatter>(
+    value: f32,
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+) -> Result<(), SerializeError> {
+    if value.is_finite() {
+        formatter.write_f32(writer, value);
+        return Ok(());
+    }
+    match cfg.non_finite_floats {
+        NonFiniteFloatHandling::Null => formatter.write_null(writer),
+        NonFiniteFloatHandling::Error => return Err(SerializeError::NonFiniteFloat),
+        NonFiniteFloatHandling::Raw => writer.write(non_finite_f64_token(value as f64).as_bytes()),
+    }
+    Ok(())
+}
+
+/// Writes an `f64`, applying [`SerializeConfig::non_finite_floats`] if the
+/// value is `NaN` or infinite - see [`NonFiniteFloatHandling`].
+fn write_f64<W: crate::JsonWrite, F: Formatter>(
+    value: f64,
+    writer: &mut W,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
+) -> Result<(), SerializeError> {
+    if value.is_finite() {
+        formatter.write_f64(writer, value);
+        return Ok(());
+    }
+    match cfg.non_finite_floats {
+        NonFiniteFloatHandling::Null => formatter.write_null(writer),
+        NonFiniteFloatHandling::Error => return Err(SerializeError::NonFiniteFloat),
+        NonFiniteFloatHandling::Raw => writer.write(non_finite_f64_token(value).as_bytes()),
+    }
+    Ok(())
+}
+
+/// The non-standard JSON token for a non-finite float, used by
+/// [`NonFiniteFloatHandling::Raw`].
+fn non_finite_f64_token(value: f64) -> &'static str {
+    if value.is_nan() {
+        "NaN"
+    } else if value.is_sign_negative() {
+        "-Infinity"
+    } else {
+        "Infinity"
+    }
+}
+
+/// A low-level, pull-style JSON writer that callers drive directly -
+/// `begin_array`/`array_value`/`end_array`, `begin_object`/`key`/`end_object`,
+/// and scalar emitters - instead of handing over a whole `Facet` value.
+/// [`serialize_array`] and the tuple/struct-variant branches of
+/// [`serialize_enum_content`] are themselves built on top of this, so a
+/// custom [`Formatter`] or [`SerializeOptions`] (comma placement,
+/// indentation, `ensure_ascii`, depth limiting) behaves identically whether
+/// serialization is driven by reflection or by hand.
+///
+/// Reach for this directly when the data to serialize isn't backed by a
+/// single `Facet` value - e.g. streaming a large array one record at a time
+/// from an iterator or a database cursor without materializing it all in
+/// memory first - while still getting comma and indentation state handled
+/// centrally rather than re-derived by the caller.
+pub struct JsonSerializer<'w, 'a, W, F> {
+    writer: &'w mut W,
+    formatter: &'w mut F,
+    cfg: SerializeConfig<'a>,
+    /// One entry per currently-open array/object, tracking whether the next
+    /// element/member written needs a separating comma first.
+    first: Vec<bool>,
+    depth: usize,
+}
+
+impl<'w, W: crate::JsonWrite, F: Formatter> JsonSerializer<'w, 'static, W, F> {
+    /// Creates a writer with compact, default [`SerializeOptions`].
+    pub fn new(writer: &'w mut W, formatter: &'w mut F) -> Self {
+        JsonSerializer {
+            writer,
+            formatter,
+            cfg: SerializeConfig::default(),
+            first: Vec::new(),
+            depth: 0,
+        }
+    }
+}
+
+impl<'w, 'a, W: crate::JsonWrite, F: Formatter> JsonSerializer<'w, 'a, W, F> {
+    /// Creates a writer using the given [`SerializeOptions`].
+    pub fn with_options(
+        writer: &'w mut W,
+        formatter: &'w mut F,
+        options: &SerializeOptions<'a>,
+    ) -> Self {
+        JsonSerializer::from_config(writer, formatter, SerializeConfig::from_options(options), 0)
+    }
+
+    fn from_config(
+        writer: &'w mut W,
+        formatter: &'w mut F,
+        cfg: SerializeConfig<'a>,
+        depth: usize,
+    ) -> Self {
+        JsonSerializer {
+            writer,
+            formatter,
+            cfg,
+            first: Vec::new(),
+            depth,
+        }
+    }
+
+    /// Begins a JSON array. Pair with [`Self::array_value`] before each
+    /// element and [`Self::end_array`] once all elements are written.
+    pub fn begin_array(&mut self) -> Result<(), SerializeError> {
+        let new_depth = self.depth + 1;
+        if new_depth > self.cfg.max_depth {
+            return Err(SerializeError::DepthLimitExceeded);
+        }
+        self.formatter.begin_array(self.writer);
+        self.first.push(true);
+        self.depth = new_depth;
+        Ok(())
+    }
+
+    /// Marks the start of the next array element, writing a separating
+    /// comma unless this is the first one since [`Self::begin_array`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a `begin_array`/`end_array` pair.
+    pub fn array_value(&mut self) {
+        let first = self
+            .first
+            .last_mut()
+            .expect("array_value called outside begin_array/end_array");
+        self.formatter.array_value_separator(self.writer, *first);
+        *first = false;
+    }
+
+    /// Ends the element started by the last [`Self::array_value`] call.
+    pub fn end_array_value(&mut self) {
+        self.formatter.end_array_value(self.writer);
+    }
+
+    /// Ends the array opened by the matching [`Self::begin_array`].
+    pub fn end_array(&mut self) {
+        self.first.pop();
+        self.depth -= 1;
+        self.formatter.end_array(self.writer);
+    }
+
+    /// Begins a JSON object. Pair with [`Self::key`] before each member's
+    /// value and [`Self::end_object`] once all members are written.
+    pub fn begin_object(&mut self) -> Result<(), SerializeError> {
+        let new_depth = self.depth + 1;
+        if new_depth > self.cfg.max_depth {
+            return Err(SerializeError::DepthLimitExceeded);
+        }
+        self.formatter.begin_object(self.writer);
+        self.first.push(true);
+        self.depth = new_depth;
+        Ok(())
+    }
+
+    /// Writes an object member's key (handling comma placement and string
+    /// escaping) - follow with [`Self::begin_object_value`], the member's
+    /// value, then [`Self::end_object_value`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a `begin_object`/`end_object` pair.
+    pub fn key(&mut self, name: &str) {
+        let first = self
+            .first
+            .last_mut()
+            .expect("key called outside begin_object/end_object");
+        self.formatter.begin_object_key(self.writer, *first);
+        *first = false;
+        write_string(self.writer, self.formatter, &self.cfg, name);
+        self.formatter.end_object_key(self.writer);
+    }
+
+    /// Marks the start of an object member's value, after [`Self::key`].
+    pub fn begin_object_value(&mut self) {
+        self.formatter.begin_object_value(self.writer);
+    }
+
+    /// Ends the value started by [`Self::begin_object_value`].
+    pub fn end_object_value(&mut self) {
+        self.formatter.end_object_value(self.writer);
+    }
+
+    /// Ends the object opened by the matching [`Self::begin_object`].
+    pub fn end_object(&mut self) {
+        self.first.pop();
+        self.depth -= 1;
+        self.formatter.end_object(self.writer);
+    }
+
+    /// Writes a JSON `null`.
+    pub fn write_null(&mut self) {
+        self.formatter.write_null(self.writer);
+    }
+
+    /// Writes a JSON boolean.
+    pub fn write_bool(&mut self, value: bool) {
+        self.formatter.write_bool(self.writer, value);
+    }
+
+    /// Writes a JSON number.
+    pub fn write_i64(&mut self, value: i64) {
+        self.formatter.write_i64(self.writer, value);
+    }
+
+    /// Writes a JSON number.
+    pub fn write_u64(&mut self, value: u64) {
+        self.formatter.write_u64(self.writer, value);
+    }
+
+    /// Writes a JSON number.
+    pub fn write_f64(&mut self, value: f64) {
+        self.formatter.write_f64(self.writer, value);
+    }
+
+    /// Writes a complete string value, quoted and escaped.
+    pub fn write_str(&mut self, value: &str) {
+        write_string(self.writer, self.formatter, &self.cfg, value);
+    }
+
+    /// Serializes a reflected `Facet` value at the current position - for
+    /// mixing streamed elements with ordinary reflection-driven
+    /// serialization, e.g. a streamed array whose elements are themselves
+    /// typed records.
+    pub fn value(&mut self, peek: Peek<'_, '_>) -> Result<(), SerializeError> {
+        self.value_with_field(peek, None)
+    }
+
+    fn value_with_field(
+        &mut self,
+        peek: Peek<'_, '_>,
+        field: Option<Field>,
+    ) -> Result<(), SerializeError> {
+        serialize_value(peek, field, self.writer, self.formatter, &self.cfg, self.depth)
+    }
+}
+
+fn serialize_array<'mem, 'facet, W: crate::JsonWrite, F: Formatter>(
     iter: facet_reflect::PeekListLikeIter<'mem, 'facet>,
     writer: &mut W,
-    indent: Option<&str>,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
     depth: usize,
 ) -> Result<(), SerializeError> {
-    writer.write(b"[");
-    let mut first = true;
+    let mut ser = JsonSerializer::from_config(writer, formatter, *cfg, depth);
+    ser.begin_array()?;
     for item in iter {
-        if !first {
-            writer.write(b",");
-        }
-        first = false;
-        write_newline(writer, indent);
-        write_indent(writer, indent, depth + 1);
-        serialize_value(item, None, writer, indent, depth + 1)?;
+        ser.array_value();
+        ser.value(item)?;
+        ser.end_array_value();
     }
-    if !first {
-        write_newline(writer, indent);
-        write_indent(writer, indent, depth);
-    }
-    writer.write(b"]");
+    ser.end_array();
     Ok(())
 }
 
-fn serialize_byte_array<W: crate::JsonWrite>(
+fn serialize_byte_array<W: crate::JsonWrite, F: Formatter>(
     bytes: &[u8],
     writer: &mut W,
-    indent: Option<&str>,
-    depth: usize,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
 ) -> Result<(), SerializeError> {
-    writer.write(b"[");
-    let mut first = true;
-    for &byte in bytes {
-        if !first {
-            writer.write(b",");
+    match cfg.byte_encoding {
+        ByteEncoding::Array => {
+            formatter.begin_array(writer);
+            let mut first = true;
+            for &byte in bytes {
+                formatter.array_value_separator(writer, first);
+                first = false;
+                formatter.write_u8(writer, byte);
+                formatter.end_array_value(writer);
+            }
+            formatter.end_array(writer);
+        }
+        ByteEncoding::Base64 => {
+            let encoded = encode_base64(bytes);
+            write_string(writer, formatter, cfg, &encoded);
+        }
+        ByteEncoding::Base64Url => {
+            let encoded = encode_base64_url(bytes);
+            write_string(writer, formatter, cfg, &encoded);
+        }
+        ByteEncoding::Hex => {
+            let encoded = encode_hex(bytes);
+            write_string(writer, formatter, cfg, &encoded);
         }
-        first = false;
-        write_newline(writer, indent);
-        write_indent(writer, indent, depth + 1);
-        writer.write(itoa::Buffer::new().format(byte).as_bytes());
-    }
-    if !first {
-        write_newline(writer, indent);
-        write_indent(writer, indent, depth);
     }
-    writer.write(b"]");
     Ok(())
 }
 
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes `bytes` as standard base64 (RFC 4648 §4, with `=` padding).
+fn encode_base64(bytes: &[u8]) -> String {
+    encode_base64_with(bytes, BASE64_ALPHABET, true)
+}
+
+/// Encodes `bytes` as URL-safe base64 (RFC 4648 §5, without padding).
+fn encode_base64_url(bytes: &[u8]) -> String {
+    encode_base64_with(bytes, BASE64_URL_ALPHABET, false)
+}
+
+fn encode_base64_with(bytes: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(alphabet[(b0 >> 2) as usize] as char);
+        out.push(alphabet[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        match b1 {
+            Some(b1) => out.push(
+                alphabet[((b1 & 0x0f) << 2 | b2.unwrap_or(0) >> 6) as usize] as char,
+            ),
+            None => {
+                if pad {
+                    out.push('=');
+                }
+            }
+        }
+        match b2 {
+            Some(b2) => out.push(alphabet[(b2 & 0x3f) as usize] as char),
+            None => {
+                if pad {
+                    out.push('=');
+                }
+            }
+        }
+    }
+    out
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encodes `bytes` as lowercase hexadecimal, two characters per byte.
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        out.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        out.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
 /// Serialize enum variant content (without any wrapper/tag)
-fn serialize_enum_content<'mem, 'facet, W: crate::JsonWrite>(
+fn serialize_enum_content<'mem, 'facet, W: crate::JsonWrite, F: Formatter>(
+    shape: &Shape,
     peek_enum: &facet_reflect::PeekEnum<'mem, 'facet>,
     variant: &facet_core::Variant,
     writer: &mut W,
-    indent: Option<&str>,
+    formatter: &mut F,
+    cfg: &SerializeConfig<'_>,
     depth: usize,
 ) -> Result<(), SerializeError> {
     if variant.data.fields.is_empty() {
         // Unit variant - serialize as null for untagged
-        writer.write(b"null");
+        formatter.write_null(writer);
     } else if variant_is_newtype_like(variant) {
         // Newtype variant - serialize the inner value directly
         let fields: Vec<_> = peek_enum.fields_for_serialize().collect();
         let (field, field_peek) = fields[0];
-        serialize_value(field_peek, Some(field), writer, indent, depth)?;
+        serialize_value(field_peek, Some(field), writer, formatter, cfg, depth + 1)?;
     } else if variant.data.kind == StructKind::Tuple || variant.data.kind == StructKind::TupleStruct
     {
         // Tuple variant - serialize as array
-        writer.write(b"[");
-        let mut first = true;
+        let mut ser = JsonSerializer::from_config(writer, formatter, *cfg, depth);
+        ser.begin_array()?;
         for (field, field_peek) in peek_enum.fields_for_serialize() {
-            if !first {
-                writer.write(b",");
-            }
-            first = false;
-            write_newline(writer, indent);
-            write_indent(writer, indent, depth + 1);
-            serialize_value(field_peek, Some(field), writer, indent, depth + 1)?;
-        }
-        if !first {
-            write_newline(writer, indent);
-            write_indent(writer, indent, depth);
+            ser.array_value();
+            ser.value_with_field(field_peek, Some(field))?;
+            ser.end_array_value();
         }
-        writer.write(b"]");
+        ser.end_array();
     } else {
         // Struct variant - serialize as object
-        writer.write(b"{");
-        let mut first = true;
-        for (field, field_peek) in peek_enum.fields_for_serialize() {
-            if !first {
-                writer.write(b",");
+        let skip_field: Vec<bool> = if cfg.skip_none_fields || cfg.skip_empty_collections {
+            peek_enum
+                .fields_for_serialize()
+                .map(|(_, value)| should_skip_field(value, cfg))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let mut ser = JsonSerializer::from_config(writer, formatter, *cfg, depth);
+        ser.begin_object()?;
+        for (i, (field, field_peek)) in peek_enum.fields_for_serialize().enumerate() {
+            if skip_field.get(i).copied().unwrap_or(false) {
+                continue;
             }
-            first = false;
-            write_newline(writer, indent);
-            write_indent(writer, indent, depth + 1);
-            crate::write_json_string(writer, field.name);
-            write_colon(writer, indent);
-            serialize_value(field_peek, Some(field), writer, indent, depth + 1)?;
+            ser.key(render_name(shape, field.name).as_ref());
+            ser.begin_object_value();
+            ser.value_with_field(field_peek, Some(field))?;
+            ser.end_object_value();
         }
-        if !first {
-            write_newline(writer, indent);
-            write_indent(writer, indent, depth);
-        }
-        writer.write(b"}");
+        ser.end_object();
     }
     Ok(())
 }
@@ -2,6 +2,8 @@
 
 use core::fmt;
 
+use alloc::vec::Vec;
+
 /// Position in the input (byte index)
 pub type Pos = usize;
 
@@ -39,6 +41,22 @@ impl Span {
     pub fn end(&self) -> Pos {
         self.start + self.len
     }
+
+    /// Resolves this span's start/end byte positions to 1-based
+    /// `(line, column)` pairs, for human-friendly diagnostics. Columns count
+    /// Unicode scalar values from the last newline, not bytes, so multibyte
+    /// content (e.g. emoji) still reports a sensible column.
+    ///
+    /// This scans `source` from scratch on every call; callers resolving
+    /// many spans against the same source should build a [`LineIndex`] once
+    /// and call [`LineIndex::line_col`] instead.
+    pub fn line_col(&self, source: &str) -> (LineCol, LineCol) {
+        let index = LineIndex::new(source);
+        (
+            index.line_col(source, self.start()),
+            index.line_col(source, self.end()),
+        )
+    }
 }
 
 impl From<Span> for miette::SourceSpan {
@@ -47,6 +65,56 @@ impl From<Span> for miette::SourceSpan {
     }
 }
 
+/// A 1-based line and column position - see [`Span::line_col`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineCol {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number, counting Unicode scalar values since the last
+    /// newline (not bytes).
+    pub col: usize,
+}
+
+/// Precomputed line-start byte offsets for a source string, so resolving
+/// many [`Span`]s to [`LineCol`]s (e.g. rendering a batch of diagnostics
+/// against the same document) is `O(log n)` per lookup instead of
+/// rescanning from the start every time, as [`Span::line_col`] does.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    /// Byte offset each line starts at; always begins with `0`.
+    line_starts: Vec<Pos>,
+}
+
+impl LineIndex {
+    /// Scans `source` once, recording the byte offset each line starts at.
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = Vec::with_capacity(1);
+        line_starts.push(0);
+        for (i, b) in source.bytes().enumerate() {
+            if b == b'\n' {
+                line_starts.push(i + 1);
+            }
+        }
+        LineIndex { line_starts }
+    }
+
+    /// Resolves a byte position in `source` (the same string this index was
+    /// built from) into a 1-based line/column, binary-searching the
+    /// precomputed line starts.
+    pub fn line_col(&self, source: &str, pos: Pos) -> LineCol {
+        let line = match self.line_starts.binary_search(&pos) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        let line_start = self.line_starts[line];
+        let col = source[line_start..pos.min(source.len())].chars().count() + 1;
+        LineCol {
+            line: line + 1,
+            col,
+        }
+    }
+}
+
 /// A value of type `T` annotated with its `Span`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Spanned<T> {
@@ -56,6 +124,18 @@ pub struct Spanned<T> {
     pub span: Span,
 }
 
+impl<T> Spanned<T> {
+    /// Returns a wrapper that displays this value with 1-based `line:col`
+    /// positions resolved against `source`, instead of the raw byte offsets
+    /// `Spanned`'s own [`Display`](fmt::Display) impl prints.
+    pub fn display_with_source<'a>(&'a self, source: &'a str) -> SpannedDisplay<'a, T> {
+        SpannedDisplay {
+            spanned: self,
+            source,
+        }
+    }
+}
+
 impl<T: fmt::Display> fmt::Display for Spanned<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -67,3 +147,21 @@ impl<T: fmt::Display> fmt::Display for Spanned<T> {
         )
     }
 }
+
+/// Displays a [`Spanned<T>`] with `line:col` positions - see
+/// [`Spanned::display_with_source`].
+pub struct SpannedDisplay<'a, T> {
+    spanned: &'a Spanned<T>,
+    source: &'a str,
+}
+
+impl<'a, T: fmt::Display> fmt::Display for SpannedDisplay<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (start, end) = self.spanned.span.line_col(self.source);
+        write!(
+            f,
+            "{} at {}:{}-{}:{}",
+            self.spanned.node, start.line, start.col, end.line, end.col
+        )
+    }
+}
@@ -0,0 +1,200 @@
+//! A dynamic, untyped JSON value for schema-less parsing.
+//!
+//! [`JsonValue`] plays the role that `serde_json::Value` plays for serde: it
+//! lets callers parse arbitrary JSON without a concrete target type, then
+//! inspect or convert it afterwards. Numbers preserve the integer-vs-float
+//! and signedness distinctions the tokenizer already makes, and strings
+//! borrow from the input when no escaping was needed, just like the scalar
+//! path of [`JsonDeserializer`](crate::deserialize::JsonDeserializer).
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use facet_core::Facet;
+
+use crate::span::Spanned;
+
+/// A dynamic JSON value.
+///
+/// Deserializing into `JsonValue` never fails on shape mismatch - any valid
+/// JSON document parses into one - so it's useful for "parse now, reflect
+/// into a typed struct later" workflows and for partial/schema-less parsing.
+#[derive(Facet, Debug, Clone, PartialEq)]
+pub enum JsonValue<'input> {
+    /// JSON `null`
+    Null,
+    /// JSON `true`/`false`
+    Bool(bool),
+    /// A signed integer that didn't fit (or wasn't needed) as `U64`
+    I64(i64),
+    /// An unsigned integer
+    U64(u64),
+    /// A signed integer wider than 64 bits
+    I128(i128),
+    /// An unsigned integer wider than 64 bits
+    U128(u128),
+    /// A floating point number (or an integer with a fractional part)
+    F64(f64),
+    /// A JSON string, borrowed from the input when it required no unescaping
+    String(Cow<'input, str>),
+    /// A JSON array
+    Array(Vec<JsonValue<'input>>),
+    /// A JSON object, preserving the original member order
+    Object(Vec<(Cow<'input, str>, JsonValue<'input>)>),
+}
+
+impl<'input> JsonValue<'input> {
+    /// Returns `true` if this is `Null`.
+    pub fn is_null(&self) -> bool {
+        matches!(self, JsonValue::Null)
+    }
+
+    /// Returns the boolean value, if this is `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `i64`, if this is a signed or unsigned integer that fits.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JsonValue::I64(n) => Some(*n),
+            JsonValue::U64(n) => i64::try_from(*n).ok(),
+            JsonValue::I128(n) => i64::try_from(*n).ok(),
+            JsonValue::U128(n) => i64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `u64`, if this is a non-negative integer that fits.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JsonValue::U64(n) => Some(*n),
+            JsonValue::I64(n) => u64::try_from(*n).ok(),
+            JsonValue::I128(n) => u64::try_from(*n).ok(),
+            JsonValue::U128(n) => u64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `f64`, if this is any numeric variant.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::F64(n) => Some(*n),
+            JsonValue::I64(n) => Some(*n as f64),
+            JsonValue::U64(n) => Some(*n as f64),
+            JsonValue::I128(n) => Some(*n as f64),
+            JsonValue::U128(n) => Some(*n as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the string slice, if this is `String`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements, if this is `Array`.
+    pub fn as_array(&self) -> Option<&[JsonValue<'input>]> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the members, if this is `Object`.
+    pub fn as_object(&self) -> Option<&[(Cow<'input, str>, JsonValue<'input>)]> {
+        match self {
+            JsonValue::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    /// Looks up a member by key, if this is `Object`.
+    pub fn get(&self, key: &str) -> Option<&JsonValue<'input>> {
+        match self {
+            JsonValue::Object(members) => members
+                .iter()
+                .find(|(k, _)| k.as_ref() == key)
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Looks up an element by index, if this is `Array`.
+    pub fn get_index(&self, index: usize) -> Option<&JsonValue<'input>> {
+        match self {
+            JsonValue::Array(items) => items.get(index),
+            _ => None,
+        }
+    }
+}
+
+/// A [`JsonValue`] tree where every array element and object member is
+/// additionally annotated with the [`Span`](crate::span::Span) of its exact
+/// source text.
+///
+/// This is the same dynamic DOM as [`JsonValue`], but for callers that need
+/// to point back at *where in the source* a particular node came from - for
+/// example to underline a specific array element in a diagnostic, rather
+/// than just the document as a whole. Deserializing into `SpannedJsonValue`
+/// never fails on shape mismatch, the same as `JsonValue`; the two differ
+/// only in whether each node carries its span.
+///
+/// Use [`JsonValue`] instead when spans aren't needed - it has no
+/// [`Spanned`] wrapping to allocate or thread through callers.
+#[derive(Facet, Debug, Clone, PartialEq)]
+pub enum SpannedJsonValue<'input> {
+    /// JSON `null`
+    Null,
+    /// JSON `true`/`false`
+    Bool(bool),
+    /// A signed integer that didn't fit (or wasn't needed) as `U64`
+    I64(i64),
+    /// An unsigned integer
+    U64(u64),
+    /// A signed integer wider than 64 bits
+    I128(i128),
+    /// An unsigned integer wider than 64 bits
+    U128(u128),
+    /// A floating point number (or an integer with a fractional part)
+    F64(f64),
+    /// A JSON string, borrowed from the input when it required no unescaping
+    String(Cow<'input, str>),
+    /// A JSON array, each element paired with its source span
+    Array(Vec<Spanned<SpannedJsonValue<'input>>>),
+    /// A JSON object, preserving the original member order, each member's
+    /// value paired with its source span
+    Object(Vec<(Cow<'input, str>, Spanned<SpannedJsonValue<'input>>)>),
+}
+
+impl<'input> SpannedJsonValue<'input> {
+    /// Discards every span, producing the plain [`JsonValue`] this tree
+    /// describes.
+    pub fn into_json_value(self) -> JsonValue<'input> {
+        match self {
+            SpannedJsonValue::Null => JsonValue::Null,
+            SpannedJsonValue::Bool(b) => JsonValue::Bool(b),
+            SpannedJsonValue::I64(n) => JsonValue::I64(n),
+            SpannedJsonValue::U64(n) => JsonValue::U64(n),
+            SpannedJsonValue::I128(n) => JsonValue::I128(n),
+            SpannedJsonValue::U128(n) => JsonValue::U128(n),
+            SpannedJsonValue::F64(n) => JsonValue::F64(n),
+            SpannedJsonValue::String(s) => JsonValue::String(s),
+            SpannedJsonValue::Array(items) => {
+                JsonValue::Array(items.into_iter().map(|item| item.node.into_json_value()).collect())
+            }
+            SpannedJsonValue::Object(members) => JsonValue::Object(
+                members
+                    .into_iter()
+                    .map(|(key, value)| (key, value.node.into_json_value()))
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -83,3 +83,21 @@ fn test_map_cow_str_keys_escaped() {
     assert!(matches!(key, Cow::Owned(_)));
     assert_eq!(&**key, "foo\nbar");
 }
+
+// Cow<[T]> tests
+
+#[derive(Debug, Facet)]
+struct CowSlice<'a> {
+    values: Cow<'a, [i32]>,
+}
+
+#[test]
+fn test_cow_slice_is_always_owned() {
+    // Unlike Cow<str>, a JSON array is parsed element by element rather than
+    // borrowed as one contiguous span, so Cow<[T]> always comes back owned
+    // even though nothing in the input needed escaping.
+    let json = r#"{"values":[1,2,3]}"#;
+    let result: CowSlice = from_str(json).unwrap();
+    assert!(matches!(result.values, Cow::Owned(_)));
+    assert_eq!(&*result.values, [1, 2, 3]);
+}
@@ -0,0 +1,27 @@
+use facet::Facet;
+use facet_json::{DeserializerOptions, JsonErrorKind, from_str_with_options};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+const JSON: &str = r#"{"x": 1, "y": 2, "z": 3}"#;
+
+#[test]
+fn test_deny_unknown_fields_option_defaults_to_ignoring_them() {
+    let point: Point = from_str_with_options(JSON, DeserializerOptions::new()).unwrap();
+    assert_eq!(point, Point { x: 1, y: 2 });
+}
+
+#[test]
+fn test_deny_unknown_fields_option_rejects_an_extra_key() {
+    let options = DeserializerOptions::new().deny_unknown_fields(true);
+    let err = from_str_with_options::<Point>(JSON, options).unwrap_err();
+    match err.kind {
+        JsonErrorKind::UnknownField { field, .. } => assert_eq!(field, "z"),
+        other => panic!("expected JsonErrorKind::UnknownField, got {other:?}"),
+    }
+}
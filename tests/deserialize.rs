@@ -128,3 +128,70 @@ fn test_custom_deserialization_enum() {
         _ => panic!("expected OpStrField variant"),
     }
 }
+
+#[test]
+fn test_from_str_relaxed_default_accepts_strict_json() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // Comment-free, strictly valid JSON must parse successfully under the
+    // default `ParseOptions` - it must not fail just because comment support
+    // isn't implemented by this build's tokenizer.
+    let data = r#"{"x": 1, "y": 2}"#;
+    let point: Point = facet_json::from_str_relaxed(data, facet_json::ParseOptions::default())
+        .expect("comment-free JSON should parse under default ParseOptions");
+    assert_eq!(point, Point { x: 1, y: 2 });
+}
+
+#[test]
+fn test_from_str_relaxed_comments_opt_in_fails_fast() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // Explicitly opting into comment support fails fast with a clear error
+    // rather than silently ignoring the comment, since this build's
+    // tokenizer has no comment-skipping support.
+    let data = "{\"x\": 1, \"y\": 2} // trailing comment\n";
+    let result: Result<Point, _> = facet_json::from_str_relaxed(
+        data,
+        facet_json::ParseOptions {
+            allow_comments: true,
+            ..Default::default()
+        },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_base64_byte_encoding_roundtrip_and_rejects_bad_length() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Payload {
+        data: Vec<u8>,
+    }
+
+    let options = facet_json::DeserializerOptions::new().byte_encoding(facet_json::ByteEncoding::Base64);
+
+    // "ABC" -> standard base64 "QUJD", a well-formed 4-character chunk.
+    let good = r#"{"data": "QUJD"}"#;
+    let payload: Payload =
+        facet_json::from_str_with_options(good, options).expect("well-formed base64 should decode");
+    assert_eq!(payload.data, b"ABC".to_vec());
+
+    // A trailing chunk of exactly one base64 character only carries 6 bits,
+    // not enough for a whole byte, so it must be rejected rather than
+    // silently decoded using a zeroed-out placeholder.
+    for bad in ["QQQQQ", "A"] {
+        let data = format!(r#"{{"data": "{bad}"}}"#);
+        let result: Result<Payload, _> = facet_json::from_str_with_options(&data, options);
+        assert!(
+            result.is_err(),
+            "base64 string {bad:?} with invalid length should be rejected"
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use facet::Facet;
+use facet_json::{DeserializerOptions, DuplicateKeyPolicy, JsonErrorKind, from_str_with_options};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+const JSON: &str = r#"{"x": 1, "y": 2, "x": 3}"#;
+
+#[test]
+fn test_duplicate_keys_default_is_last_wins() {
+    let point: Point = from_str_with_options(JSON, DeserializerOptions::new()).unwrap();
+    assert_eq!(point, Point { x: 3, y: 2 });
+}
+
+#[test]
+fn test_duplicate_keys_first_wins_keeps_the_first_occurrence() {
+    let options = DeserializerOptions::new().duplicate_keys(DuplicateKeyPolicy::FirstWins);
+    let point: Point = from_str_with_options(JSON, options).unwrap();
+    assert_eq!(point, Point { x: 1, y: 2 });
+}
+
+#[test]
+fn test_duplicate_keys_error_policy_reports_the_repeated_key() {
+    let options = DeserializerOptions::new().duplicate_keys(DuplicateKeyPolicy::Error);
+    let err = from_str_with_options::<Point>(JSON, options).unwrap_err();
+    match err.kind {
+        JsonErrorKind::DuplicateKey { key } => assert_eq!(key, "x"),
+        other => panic!("expected JsonErrorKind::DuplicateKey, got {other:?}"),
+    }
+}
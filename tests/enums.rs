@@ -585,3 +585,100 @@ fn test_untagged_unit_variant() {
     let json_val = facet_json::to_string(&val);
     assert_eq!(json_val, "42");
 }
+
+#[test]
+fn test_untagged_newtype_variants_deserialize() {
+    #[derive(Debug, Facet, PartialEq)]
+    #[repr(u8)]
+    #[facet(untagged)]
+    enum StringOrInt {
+        Int(i64),
+        Str(String),
+    }
+
+    let int_val: StringOrInt = from_str("42").unwrap();
+    assert_eq!(int_val, StringOrInt::Int(42));
+
+    let str_val: StringOrInt = from_str(r#""hello""#).unwrap();
+    assert_eq!(str_val, StringOrInt::Str("hello".to_string()));
+}
+
+#[test]
+fn test_untagged_struct_variants_deserialize() {
+    #[derive(Debug, Facet, PartialEq)]
+    #[repr(C)]
+    #[facet(untagged)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle { radius: f64 },
+        Rectangle { width: f64, height: f64 },
+    }
+
+    // The fields present in the input disambiguate which variant is tried
+    // first successfully - `radius` only matches `Circle`, `width`/`height`
+    // only match `Rectangle`.
+    let circle: Shape = from_str(r#"{"radius":5.0}"#).unwrap();
+    assert_eq!(circle, Shape::Circle { radius: 5.0 });
+
+    let rect: Shape = from_str(r#"{"width":10.0,"height":20.0}"#).unwrap();
+    assert_eq!(
+        rect,
+        Shape::Rectangle {
+            width: 10.0,
+            height: 20.0
+        }
+    );
+}
+
+#[test]
+fn test_untagged_unit_variant_deserialize() {
+    #[derive(Debug, Facet, PartialEq)]
+    #[repr(u8)]
+    #[facet(untagged)]
+    enum MaybeNull {
+        Null,
+        Value(i32),
+    }
+
+    let null_val: MaybeNull = from_str("null").unwrap();
+    assert_eq!(null_val, MaybeNull::Null);
+
+    let val: MaybeNull = from_str("42").unwrap();
+    assert_eq!(val, MaybeNull::Value(42));
+}
+
+#[test]
+fn test_untagged_struct_variant_rejects_ambiguous_input() {
+    // Neither variant's fields are a subset of the input's, so the
+    // backtracking deserializer must exhaust every variant and report
+    // failure rather than guessing.
+    #[derive(Debug, Facet, PartialEq)]
+    #[repr(C)]
+    #[facet(untagged)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle { radius: f64 },
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let result: Result<Shape, _> = from_str(r#"{"side":5.0}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_untagged_struct_variant_rejects_one_missing_a_required_field() {
+    // `Rectangle` is missing `height`, so it must be rejected rather than
+    // silently accepted with an uninitialized field - `Circle` doesn't
+    // match either (no `radius`), so the whole deserialize fails.
+    #[derive(Debug, Facet, PartialEq)]
+    #[repr(C)]
+    #[facet(untagged)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle { radius: f64 },
+        Rectangle { width: f64, height: f64 },
+    }
+
+    let result: Result<Shape, _> = from_str(r#"{"width":10.0}"#);
+    assert!(result.is_err());
+}
@@ -0,0 +1,53 @@
+use facet::Facet;
+use facet_json::{JsonErrorKind, from_str, from_str_collecting};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq, Default)]
+struct Inner {
+    value: i32,
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct Outer {
+    age: u8,
+    nested: Inner,
+    tag: String,
+}
+
+#[test]
+fn test_from_str_collecting_gathers_every_scalar_mismatch() {
+    let json = r#"{"age": "oops", "nested": {"value": 1}, "tag": 123}"#;
+
+    let err = from_str_collecting::<Outer>(json).unwrap_err();
+    match err.kind {
+        JsonErrorKind::Multiple(errors) => assert_eq!(errors.len(), 2),
+        other => panic!("expected JsonErrorKind::Multiple, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_from_str_stops_at_the_first_mismatch_without_collecting() {
+    let json = r#"{"age": "oops", "nested": {"value": 1}, "tag": 123}"#;
+
+    let err = from_str::<Outer>(json).unwrap_err();
+    assert!(!matches!(err.kind, JsonErrorKind::Multiple(_)));
+}
+
+#[test]
+fn test_from_str_collecting_resyncs_after_a_malformed_nested_field() {
+    // `nested` is a struct field, not a scalar leaf, so a type mismatch here
+    // (a number instead of an object) fails without consuming a clean single
+    // token the way a scalar leaf would. Error-accumulation mode must still
+    // resynchronize to the next sibling key so the mismatch on `tag` is
+    // reached and recorded too, instead of the whole object aborting.
+    let json = r#"{"age": 1, "nested": 42, "tag": 999}"#;
+
+    let err = from_str_collecting::<Outer>(json).unwrap_err();
+    match err.kind {
+        JsonErrorKind::Multiple(errors) => {
+            assert_eq!(errors.len(), 2);
+            assert!(matches!(errors[0].kind, JsonErrorKind::UnexpectedToken { .. }));
+        }
+        other => panic!("expected JsonErrorKind::Multiple, got {other:?}"),
+    }
+}
@@ -0,0 +1,35 @@
+use facet::Facet;
+use facet_json::{Formatter, JsonWrite, to_writer_with_formatter};
+use facet_testhelpers::test;
+
+#[derive(Default)]
+struct ShoutingFormatter;
+
+impl Formatter for ShoutingFormatter {
+    fn write_bool<W: JsonWrite>(&mut self, writer: &mut W, value: bool) {
+        writer.write(if value { b"YES" } else { b"NO" });
+    }
+}
+
+#[derive(Facet, Debug)]
+struct Flags {
+    enabled: bool,
+    visible: bool,
+}
+
+#[test]
+fn test_custom_formatter_overrides_a_single_hook() {
+    let flags = Flags {
+        enabled: true,
+        visible: false,
+    };
+
+    let mut buf = Vec::new();
+    to_writer_with_formatter(&flags, &mut buf, ShoutingFormatter::default()).unwrap();
+    let out = String::from_utf8(buf).unwrap();
+
+    // The overridden hook changes how booleans are written, but every other
+    // hook still falls back to CompactFormatter's default (no whitespace,
+    // standard object/array delimiters).
+    assert_eq!(out, r#"{"enabled":YES,"visible":NO}"#);
+}
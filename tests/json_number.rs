@@ -0,0 +1,33 @@
+use facet::Facet;
+use facet_json::{JsonNumber, from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn test_json_number_preserves_exact_digits() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Wrapper {
+        value: JsonNumber<'static>,
+    }
+
+    // A 128-bit-wide integer literal that would lose precision (or simply
+    // not fit) if parsed into any native integer/float type.
+    let json = r#"{"value": 123456789012345678901234567890}"#;
+    let wrapper: Wrapper = from_str(json).unwrap();
+    assert_eq!(wrapper.value.as_str(), "123456789012345678901234567890");
+
+    let out = to_string(&wrapper);
+    assert_eq!(out, json.replace(' ', ""));
+}
+
+#[test]
+fn test_json_number_conversions() {
+    let n = JsonNumber::from_borrowed("42");
+    assert_eq!(n.as_i64(), Some(42));
+    assert_eq!(n.as_u64(), Some(42));
+    assert_eq!(n.as_f64(), Some(42.0));
+
+    let too_big = JsonNumber::from_borrowed("99999999999999999999999999999999999999");
+    assert_eq!(too_big.as_i64(), None);
+    assert_eq!(too_big.as_u64(), None);
+    assert!(too_big.as_f64().is_some());
+}
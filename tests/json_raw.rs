@@ -0,0 +1,77 @@
+use facet::Facet;
+use facet_json::{JsonRaw, RawValue, from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn test_json_raw_round_trips_a_struct_value() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Wrapper<'a> {
+        value: JsonRaw<'a>,
+    }
+
+    let json = r#"{"value":{"a":1,"b":[2,3]}}"#;
+    let wrapper: Wrapper = from_str(json).unwrap();
+    assert_eq!(wrapper.value.get(), r#"{"a":1,"b":[2,3]}"#);
+
+    let out = to_string(&wrapper);
+    assert_eq!(out, json);
+}
+
+#[test]
+fn test_json_raw_preserves_interior_whitespace() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Wrapper<'a> {
+        value: JsonRaw<'a>,
+    }
+
+    let json = r#"{"value": { "a" : 1,  "b" : 2 } }"#;
+    let wrapper: Wrapper = from_str(json).unwrap();
+    assert_eq!(wrapper.value.get(), r#"{ "a" : 1,  "b" : 2 }"#);
+}
+
+#[test]
+fn test_json_raw_writes_unquoted_next_to_sibling_fields() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Wrapper<'a> {
+        before: i32,
+        value: JsonRaw<'a>,
+        after: i32,
+    }
+
+    let wrapper = Wrapper {
+        before: 1,
+        value: JsonRaw::from_borrowed(r#"{"nested":true}"#),
+        after: 2,
+    };
+    let out = to_string(&wrapper);
+    assert_eq!(out, r#"{"before":1,"value":{"nested":true},"after":2}"#);
+}
+
+#[test]
+fn test_json_raw_captures_primitive_values_verbatim() {
+    let n: JsonRaw = from_str("42").unwrap();
+    assert_eq!(n.get(), "42");
+
+    let s: JsonRaw = from_str(r#""hello""#).unwrap();
+    assert_eq!(s.get(), r#""hello""#);
+
+    let b: JsonRaw = from_str("true").unwrap();
+    assert_eq!(b.get(), "true");
+}
+
+#[test]
+fn test_json_raw_into_owned() {
+    let json = r#"[1,2,3]"#;
+    let borrowed: JsonRaw = from_str(json).unwrap();
+    let owned: JsonRaw<'static> = borrowed.into_owned();
+    assert_eq!(owned.get(), "[1,2,3]");
+}
+
+#[test]
+fn test_raw_value_alias_is_interchangeable_with_json_raw() {
+    // `RawValue` is just a name alias for `JsonRaw`, for callers porting
+    // code off `serde_json::value::RawValue` - same type, same behavior.
+    let value: RawValue = from_str(r#"{"x":1}"#).unwrap();
+    assert_eq!(value.get(), r#"{"x":1}"#);
+    assert_eq!(to_string(&value), r#"{"x":1}"#);
+}
@@ -0,0 +1,40 @@
+use facet_json::{JsonValue, SpannedJsonValue, from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn test_json_value_parses_mixed_document() {
+    let json = r#"{"name": "ferris", "age": 7, "tags": ["rust", "crab"], "active": true, "nickname": null}"#;
+    let value: JsonValue = from_str(json).unwrap();
+
+    let obj = value.as_object().expect("top-level value should be an object");
+    assert_eq!(obj.len(), 5);
+    assert_eq!(value.get("name").and_then(|v| v.as_str()), Some("ferris"));
+    assert_eq!(value.get("age").and_then(|v| v.as_u64()), Some(7));
+    assert_eq!(value.get("active").and_then(|v| v.as_bool()), Some(true));
+    assert!(value.get("nickname").unwrap().is_null());
+
+    let tags = value
+        .get("tags")
+        .and_then(|v| v.as_array())
+        .expect("tags should be an array");
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[0].as_str(), Some("rust"));
+    assert_eq!(tags[1].as_str(), Some("crab"));
+}
+
+#[test]
+fn test_json_value_roundtrips_through_serialize() {
+    let json = r#"{"a":1,"b":[true,false,null]}"#;
+    let value: JsonValue = from_str(json).unwrap();
+    let out = to_string(&value);
+    let reparsed: JsonValue = from_str(&out).unwrap();
+    assert_eq!(value, reparsed);
+}
+
+#[test]
+fn test_spanned_json_value_tracks_node_positions() {
+    let json = r#"{"x": 42}"#;
+    let spanned: SpannedJsonValue = from_str(json).unwrap();
+    let plain = spanned.into_json_value();
+    assert_eq!(plain.get("x").and_then(|v| v.as_i64()), Some(42));
+}
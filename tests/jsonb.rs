@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_json::{JsonErrorKind, JsonValue, from_jsonb};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Doc {
+    x: i32,
+}
+
+#[test]
+fn test_from_jsonb_decodes_small_object_with_inline_int16() {
+    // Hand-built MySQL-style JSONB tape for `{"x": 5}`:
+    //   tag=SMALL_OBJECT, count=1, size=0 (unused),
+    //   one key entry (offset=12, len=1) and one value entry
+    //   (tag=INT16, inline value=5), followed by the key byte "x".
+    let doc: &[u8] = &[
+        0x00, // tag: SMALL_OBJECT
+        0x01, 0x00, // count = 1
+        0x00, 0x00, // size (unused by the decoder)
+        0x0C, 0x00, // key entry: key_offset = 12
+        0x01, 0x00, // key entry: key_len = 1
+        0x05, // value entry: value_tag = INT16
+        0x05, 0x00, // value entry: inline i16 value = 5
+        b'x', // key bytes, at offset 12
+    ];
+
+    let value: Doc = from_jsonb(doc).unwrap();
+    assert_eq!(value, Doc { x: 5 });
+}
+
+#[test]
+fn test_from_jsonb_rejects_empty_document() {
+    let result: Result<Doc, _> = from_jsonb(&[]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_jsonb_rejects_a_truncated_document() {
+    // A SMALL_OBJECT header declaring one key/value pair, with the key and
+    // value entry tables (and everything after) missing entirely.
+    let doc: &[u8] = &[0x00, 0x01, 0x00, 0x00, 0x00];
+    let result: Result<Doc, _> = from_jsonb(doc);
+    assert!(result.is_err());
+}
+
+#[derive(Facet, Debug, PartialEq)]
+struct LargeDoc {
+    name: String,
+    value: f64,
+}
+
+#[test]
+fn test_from_jsonb_decodes_large_object_with_offset_string_and_double() {
+    // Hand-built tape for `{"name": "hi", "value": 3.5}` using the
+    // LARGE_OBJECT layout (u32 counts/offsets), where both values are
+    // offset-based rather than inline: STRING and DOUBLE are never inline,
+    // even in the large format.
+    let doc: &[u8] = &[
+        0x01, // tag: LARGE_OBJECT
+        0x02, 0x00, 0x00, 0x00, // count = 2
+        0x00, 0x00, 0x00, 0x00, // size (unused)
+        0x1F, 0x00, 0x00, 0x00, // key entry 0: key_offset = 31
+        0x04, 0x00, // key entry 0: key_len = 4
+        0x23, 0x00, 0x00, 0x00, // key entry 1: key_offset = 35
+        0x05, 0x00, // key entry 1: key_len = 5
+        0x0C, // value entry 0: value_tag = STRING
+        0x28, 0x00, 0x00, 0x00, // value entry 0: offset = 40
+        0x0B, // value entry 1: value_tag = DOUBLE
+        0x2B, 0x00, 0x00, 0x00, // value entry 1: offset = 43
+        b'n', b'a', b'm', b'e', // key bytes, at offset 31
+        b'v', b'a', b'l', b'u', b'e', // key bytes, at offset 35
+        0x02, b'h', b'i', // string value at offset 40: varlen=2, "hi"
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x40, // f64 3.5 at offset 43
+    ];
+
+    let value: LargeDoc = from_jsonb(doc).unwrap();
+    assert_eq!(
+        value,
+        LargeDoc {
+            name: "hi".to_string(),
+            value: 3.5,
+        }
+    );
+}
+
+#[test]
+fn test_from_jsonb_decodes_large_array_with_inline_int32_and_offset_uint64() {
+    // Hand-built tape for `[1000000, 9999999999]` using the LARGE_ARRAY
+    // layout: INT32 is inline in the large format (unlike small arrays),
+    // while UINT64 is still offset-based.
+    let doc: &[u8] = &[
+        0x03, // tag: LARGE_ARRAY
+        0x02, 0x00, 0x00, 0x00, // count = 2
+        0x00, 0x00, 0x00, 0x00, // size (unused)
+        0x07, // entry 0: value_tag = INT32
+        0x40, 0x42, 0x0F, 0x00, // entry 0: inline i32 = 1_000_000
+        0x0A, // entry 1: value_tag = UINT64
+        0x13, 0x00, 0x00, 0x00, // entry 1: offset = 19
+        0xFF, 0xE3, 0x0B, 0x54, 0x02, 0x00, 0x00, 0x00, // u64 9_999_999_999 at offset 19
+    ];
+
+    let value: Vec<i64> = from_jsonb(doc).unwrap();
+    assert_eq!(value, vec![1_000_000, 9_999_999_999]);
+}
+
+#[test]
+fn test_from_jsonb_decodes_small_object_into_a_map() {
+    // Hand-built tape for `{"a": 1, "b": 2}`, decoded through
+    // `read_object_into_map` rather than `read_object` because the target
+    // type is a map instead of a struct.
+    let doc: &[u8] = &[
+        0x00, // tag: SMALL_OBJECT
+        0x02, 0x00, // count = 2
+        0x00, 0x00, // size (unused)
+        0x13, 0x00, // key entry 0: key_offset = 19
+        0x01, 0x00, // key entry 0: key_len = 1
+        0x14, 0x00, // key entry 1: key_offset = 20
+        0x01, 0x00, // key entry 1: key_len = 1
+        0x05, // value entry 0: value_tag = INT16
+        0x01, 0x00, // value entry 0: inline i16 = 1
+        0x05, // value entry 1: value_tag = INT16
+        0x02, 0x00, // value entry 1: inline i16 = 2
+        b'a', // key bytes, at offset 19
+        b'b', // key bytes, at offset 20
+    ];
+
+    let value: HashMap<String, i32> = from_jsonb(doc).unwrap();
+    assert_eq!(value.get("a"), Some(&1));
+    assert_eq!(value.get("b"), Some(&2));
+}
+
+/// Builds a JSONB tape of `levels` nested SMALL_ARRAYs (each containing
+/// exactly one element), terminated by an empty SMALL_ARRAY. Array entries
+/// are never inline, so every level is offset-based, just like MySQL's own
+/// encoding of deeply nested containers.
+fn nested_small_arrays(levels: usize) -> Vec<u8> {
+    const SMALL_ARRAY: u8 = 0x02;
+    const LEVEL_LEN: usize = 8; // tag + count + size + (entry tag + entry slot)
+
+    let mut doc = Vec::with_capacity(levels * LEVEL_LEN + 5);
+    for level in 0..levels {
+        let child_offset = ((level + 1) * LEVEL_LEN) as u16;
+        doc.push(SMALL_ARRAY);
+        doc.extend_from_slice(&1u16.to_le_bytes()); // count = 1
+        doc.extend_from_slice(&0u16.to_le_bytes()); // size (unused)
+        doc.push(SMALL_ARRAY); // entry: value_tag
+        doc.extend_from_slice(&child_offset.to_le_bytes()); // entry: offset
+    }
+    // The innermost, empty array.
+    doc.push(SMALL_ARRAY);
+    doc.extend_from_slice(&0u16.to_le_bytes()); // count = 0
+    doc.extend_from_slice(&0u16.to_le_bytes()); // size (unused)
+    doc
+}
+
+#[test]
+fn test_from_jsonb_decodes_arrays_nested_within_the_depth_limit() {
+    let doc = nested_small_arrays(5);
+    let value: JsonValue = from_jsonb(&doc).unwrap();
+
+    let mut current = &value;
+    for _ in 0..5 {
+        let items = current.as_array().expect("expected an array");
+        assert_eq!(items.len(), 1);
+        current = &items[0];
+    }
+    assert_eq!(current.as_array(), Some(&[][..]));
+}
+
+#[test]
+fn test_from_jsonb_rejects_arrays_nested_past_the_depth_limit() {
+    // 128 levels of wrapping arrays put the innermost (empty) array at
+    // exactly `DEFAULT_MAX_DEPTH`, which the guard must reject rather than
+    // recurse into and risk overflowing the stack.
+    let doc = nested_small_arrays(128);
+    let result: Result<JsonValue, _> = from_jsonb(&doc);
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err.kind,
+        JsonErrorKind::DepthLimitExceeded { max_depth: 128 }
+    ));
+}
@@ -0,0 +1,96 @@
+use facet::Facet;
+use facet_json::{
+    DeserializerOptions, JsonErrorKind, JsonValue, SerializeError, SerializeOptions,
+    from_str_with_options, to_string_with,
+};
+use facet_testhelpers::test;
+
+/// A self-referential type whose JSON encoding nests one object per level,
+/// e.g. depth 2 is `{"child":{"child":null}}`. Unlike [`JsonValue`] (which
+/// the deserializer parses through its own unguarded recursive descent, see
+/// `deserialize_json_value`), going through this struct's `Option<Box<Self>>`
+/// field exercises the same guarded `deserialize_into` recursion that a
+/// real nested struct/array target would.
+#[derive(Facet, Debug, PartialEq)]
+struct Nested {
+    child: Option<Box<Nested>>,
+}
+
+/// Builds the JSON source text for `levels` nested `Nested` objects.
+fn nested_json(levels: usize) -> String {
+    let mut json = "null".to_string();
+    for _ in 0..levels {
+        json = format!(r#"{{"child":{json}}}"#);
+    }
+    json
+}
+
+#[test]
+fn test_deserialize_accepts_shallow_nesting() {
+    let json = nested_json(5);
+    let result: Nested = from_str_with_options(&json, DeserializerOptions::new()).unwrap();
+    let mut current = &result;
+    for _ in 0..4 {
+        current = current.child.as_deref().expect("expected another level");
+    }
+    assert!(current.child.is_none());
+}
+
+#[test]
+fn test_deserialize_rejects_nesting_past_the_default_max_depth() {
+    // Every JSON nesting level recurses through at least one guarded
+    // `deserialize_into` call, so 1000 levels is certain to trip the
+    // default 128-deep limit well before the stack itself is at risk.
+    let json = nested_json(1000);
+    let err = from_str_with_options::<Nested>(&json, DeserializerOptions::new()).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        JsonErrorKind::DepthLimitExceeded { max_depth: 128 }
+    ));
+}
+
+#[test]
+fn test_deserialize_respects_a_custom_max_depth() {
+    let json = nested_json(1000);
+    let options = DeserializerOptions::new().max_depth(3);
+    let err = from_str_with_options::<Nested>(&json, options).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        JsonErrorKind::DepthLimitExceeded { max_depth: 3 }
+    ));
+}
+
+/// Builds a `JsonValue` array nested `levels` deep, e.g. `levels=3` is
+/// `[[[[]]]]`.
+fn nested_json_value(levels: usize) -> JsonValue<'static> {
+    let mut value = JsonValue::Array(Vec::new());
+    for _ in 0..levels {
+        value = JsonValue::Array(vec![value]);
+    }
+    value
+}
+
+#[test]
+fn test_serialize_accepts_nesting_within_the_default_max_depth() {
+    // `nested_json_value(128)` is built from 128 wrapping arrays around an
+    // innermost empty array, so serializing it recurses to exactly
+    // `depth == 128` - the default limit - and must still succeed.
+    let value = nested_json_value(128);
+    let out = to_string_with(&value, SerializeOptions::new()).unwrap();
+    assert_eq!(out, "[".repeat(129) + &"]".repeat(129));
+}
+
+#[test]
+fn test_serialize_rejects_nesting_past_the_default_max_depth() {
+    let value = nested_json_value(129);
+    let err = to_string_with(&value, SerializeOptions::new()).unwrap_err();
+    assert!(matches!(err, SerializeError::DepthLimitExceeded));
+}
+
+#[test]
+fn test_serialize_respects_a_custom_max_depth() {
+    let value = nested_json_value(5);
+    let options = SerializeOptions::new().max_depth(3);
+    let err = to_string_with(&value, options).unwrap_err();
+    assert!(matches!(err, SerializeError::DepthLimitExceeded));
+}
@@ -0,0 +1,40 @@
+use facet::Facet;
+use facet_json::{JsonDeserializer, from_slice_iter, from_str_iter};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn test_from_str_iter_reads_concatenated_values() {
+    let input = r#"{"x":1,"y":2}{"x":3,"y":4}   {"x":5,"y":6}"#;
+    let values: Vec<Point> = from_str_iter(input).map(Result::unwrap).collect();
+    assert_eq!(
+        values,
+        vec![
+            Point { x: 1, y: 2 },
+            Point { x: 3, y: 4 },
+            Point { x: 5, y: 6 },
+        ]
+    );
+}
+
+#[test]
+fn test_from_slice_iter_stops_cleanly_at_end_of_input() {
+    let input = br#"{"x":1,"y":2}"#;
+    let mut iter = from_slice_iter::<Point>(input);
+    assert_eq!(iter.next().unwrap().unwrap(), Point { x: 1, y: 2 });
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_iter_lines_reads_newline_delimited_values() {
+    let input = b"{\"x\":1,\"y\":2}\n{\"x\":3,\"y\":4}\n";
+    let values: Vec<Point> = JsonDeserializer::iter_lines(input)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(values, vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+}
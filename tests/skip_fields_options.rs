@@ -0,0 +1,154 @@
+use facet::Facet;
+use facet_json::{SerializeOptions, to_string_with};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug)]
+struct Point {
+    x: i32,
+    y: Option<i32>,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_skip_none_fields_on_a_plain_struct() {
+    let with_value = Point {
+        x: 1,
+        y: Some(2),
+        tags: vec!["a".to_string()],
+    };
+    let out = to_string_with(&with_value, SerializeOptions::new().skip_none_fields(true)).unwrap();
+    assert_eq!(out, r#"{"x":1,"y":2,"tags":["a"]}"#);
+
+    let with_none = Point {
+        x: 1,
+        y: None,
+        tags: vec!["a".to_string()],
+    };
+    let out = to_string_with(&with_none, SerializeOptions::new().skip_none_fields(true)).unwrap();
+    assert_eq!(out, r#"{"x":1,"tags":["a"]}"#);
+
+    // Without the option, the field is still written as `null`.
+    let out = to_string_with(&with_none, SerializeOptions::new()).unwrap();
+    assert_eq!(out, r#"{"x":1,"y":null,"tags":["a"]}"#);
+}
+
+#[test]
+fn test_skip_empty_collections_on_a_plain_struct() {
+    let empty = Point {
+        x: 1,
+        y: Some(2),
+        tags: vec![],
+    };
+    let out = to_string_with(&empty, SerializeOptions::new().skip_empty_collections(true)).unwrap();
+    assert_eq!(out, r#"{"x":1,"y":2}"#);
+
+    // Without the option, the empty collection is still written as `[]`.
+    let out = to_string_with(&empty, SerializeOptions::new()).unwrap();
+    assert_eq!(out, r#"{"x":1,"y":2,"tags":[]}"#);
+}
+
+#[test]
+fn test_skip_none_and_empty_collections_on_an_internally_tagged_struct_variant() {
+    #[derive(Facet, Debug)]
+    #[repr(C)]
+    #[facet(tag = "type")]
+    #[allow(dead_code)]
+    enum Event {
+        Clicked { x: i32, label: Option<String> },
+        Scrolled { amount: i32, path: Vec<String> },
+    }
+
+    let clicked = Event::Clicked {
+        x: 5,
+        label: None,
+    };
+    let out = to_string_with(
+        &clicked,
+        SerializeOptions::new()
+            .skip_none_fields(true)
+            .skip_empty_collections(true),
+    )
+    .unwrap();
+    assert_eq!(out, r#"{"type":"Clicked","x":5}"#);
+
+    let scrolled = Event::Scrolled {
+        amount: 3,
+        path: vec![],
+    };
+    let out = to_string_with(
+        &scrolled,
+        SerializeOptions::new()
+            .skip_none_fields(true)
+            .skip_empty_collections(true),
+    )
+    .unwrap();
+    assert_eq!(out, r#"{"type":"Scrolled","amount":3}"#);
+}
+
+#[test]
+fn test_skip_none_fields_on_an_internally_tagged_newtype_variant() {
+    #[derive(Facet, Debug)]
+    struct Inner {
+        id: i32,
+        note: Option<String>,
+    }
+
+    #[derive(Facet, Debug)]
+    #[repr(C)]
+    #[facet(tag = "type")]
+    #[allow(dead_code)]
+    enum Wrapper {
+        Thing(Inner),
+    }
+
+    let value = Wrapper::Thing(Inner {
+        id: 1,
+        note: None,
+    });
+    let out = to_string_with(&value, SerializeOptions::new().skip_none_fields(true)).unwrap();
+    // Newtype-of-struct flattens `Inner`'s fields at the tag's level.
+    assert_eq!(out, r#"{"type":"Thing","id":1}"#);
+}
+
+#[test]
+fn test_skip_none_and_empty_collections_on_an_externally_tagged_struct_variant() {
+    #[derive(Facet, Debug)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle {
+            radius: f64,
+            label: Option<String>,
+        },
+        Square {
+            side: f64,
+            tags: Vec<String>,
+        },
+    }
+
+    let circle = Shape::Circle {
+        radius: 1.0,
+        label: None,
+    };
+    let out = to_string_with(
+        &circle,
+        SerializeOptions::new()
+            .skip_none_fields(true)
+            .skip_empty_collections(true),
+    )
+    .unwrap();
+    assert_eq!(out, r#"{"Circle":{"radius":1.0}}"#);
+
+    let square = Shape::Square {
+        side: 2.0,
+        tags: vec![],
+    };
+    let out = to_string_with(
+        &square,
+        SerializeOptions::new()
+            .skip_none_fields(true)
+            .skip_empty_collections(true),
+    )
+    .unwrap();
+    assert_eq!(out, r#"{"Square":{"side":2.0}}"#);
+}
@@ -0,0 +1,41 @@
+use facet_json::{CompactFormatter, JsonSerializer};
+use facet_testhelpers::test;
+
+#[test]
+fn test_json_serializer_streams_an_array_without_a_facet_value() {
+    let mut buf = Vec::new();
+    let mut formatter = CompactFormatter;
+    let mut ser = JsonSerializer::new(&mut buf, &mut formatter);
+
+    ser.begin_array().unwrap();
+    for n in [1u64, 2, 3] {
+        ser.array_value();
+        ser.write_u64(n);
+        ser.end_array_value();
+    }
+    ser.end_array();
+
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(out, "[1,2,3]");
+}
+
+#[test]
+fn test_json_serializer_streams_an_object() {
+    let mut buf = Vec::new();
+    let mut formatter = CompactFormatter;
+    let mut ser = JsonSerializer::new(&mut buf, &mut formatter);
+
+    ser.begin_object().unwrap();
+    ser.key("name");
+    ser.begin_object_value();
+    ser.write_str("ferris");
+    ser.end_object_value();
+    ser.key("legs");
+    ser.begin_object_value();
+    ser.write_u64(8);
+    ser.end_object_value();
+    ser.end_object();
+
+    let out = String::from_utf8(buf).unwrap();
+    assert_eq!(out, r#"{"name":"ferris","legs":8}"#);
+}